@@ -4,6 +4,17 @@ use std::env;
 use std::fs;
 use std::path::Path;
 
+#[path = "build/icon_changes.rs"]
+mod icon_changes;
+#[path = "build/service_key.rs"]
+mod service_key;
+#[path = "build/svg.rs"]
+mod svg;
+
+use icon_changes::{IconChange, read_icon_changes, resolve_renames};
+use service_key::service_key;
+use svg::validate_svg;
+
 /// Ensure identifier doesn't start with a number by prefixing with underscore or word
 fn sanitize_identifier(name: &str) -> String {
     let camel = name.to_upper_camel_case();
@@ -28,16 +39,149 @@ fn category_to_camel_case(name: &str) -> String {
         .to_upper_camel_case()
 }
 
+/// `(category_dir_name, category_snake, category_camel)`
+type Category = (String, String, String);
+
+/// `(variant_name, file_name, asset_path)`
+type IconEntry = (String, String, String);
+
+/// `[(key, variant_name)]`, sorted by key
+type LookupTable = Vec<(String, String)>;
+
+/// Bit offset of the category index in `RemixIconName` discriminants; the bits below it hold the
+/// icon's discriminant within its category enum.
+const CATEGORY_SHIFT: usize = 10;
+
+/// Parse `assets/logo-services.txt` into sorted `(alias, variant)` and `(domain, variant)` tables
+/// of the `logos` icons in `entries`.
+///
+/// Each non-comment line is `<logo> [<alias>...] [<domain>...]`, where `<logo>` is a label
+/// without its `-line`/`-fill` suffix and resolves to the filled variant if there is one. Words
+/// containing a `.` are domains.
+fn read_logo_services(path: &Path, entries: &[IconEntry]) -> (LookupTable, LookupTable) {
+    let Ok(content) = fs::read_to_string(path) else {
+        return (Vec::new(), Vec::new());
+    };
+
+    let mut services = BTreeMap::new();
+    let mut domains = BTreeMap::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let location = format!("{}:{}", path.display(), index + 1);
+        let mut words = line.split_whitespace();
+        let logo = words.next().unwrap();
+        let variant = [
+            format!("{}-fill", logo),
+            format!("{}-line", logo),
+            logo.to_string(),
+        ]
+        .iter()
+        .find_map(|label| entries.iter().find(|entry| &entry.1 == label))
+        .map(|entry| entry.0.clone())
+        .unwrap_or_else(|| panic!("{}: no `logos` icon named `{}`", location, logo));
+
+        for word in std::iter::once(logo).chain(words) {
+            let (table, key) = if word.contains('.') {
+                (&mut domains, word.to_ascii_lowercase())
+            } else {
+                (&mut services, service_key(word))
+            };
+            if let Some(other) = table.insert(key.clone(), variant.clone())
+                && other != variant
+            {
+                panic!("{}: `{}` is already listed for `{}`", location, key, other);
+            }
+        }
+    }
+    (
+        services.into_iter().collect(),
+        domains.into_iter().collect(),
+    )
+}
+
+/// Read the upstream RemixIcon version from `[package.metadata.remixicon]` in `Cargo.toml`.
+fn upstream_version(manifest: &Path) -> String {
+    let content = fs::read_to_string(manifest).unwrap();
+    let mut in_section = false;
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_section = line == "[package.metadata.remixicon]";
+        } else if in_section && let Some(value) = line.strip_prefix("version") {
+            return value
+                .trim_start_matches([' ', '='])
+                .trim_matches('"')
+                .to_string();
+        }
+    }
+    panic!("missing `version` in [package.metadata.remixicon]");
+}
+
+/// Derive an accessible label from a RemixIcon label: the style suffix is dropped and words are
+/// separated by spaces, e.g. `arrow-up-s-line` becomes `arrow up s`.
+fn accessible_label(label: &str) -> String {
+    label
+        .strip_suffix("-line")
+        .or_else(|| label.strip_suffix("-fill"))
+        .unwrap_or(label)
+        .replace('-', " ")
+}
+
+/// Pair a category's outlined and filled icons under a style-agnostic base name.
+///
+/// Returns `(base_variant, line_variant, fill_variant)` sorted by base label; icons without both
+/// a `-line` and a `-fill` variant keep their own name and resolve to themselves.
+fn icon_bases(entries: &[IconEntry]) -> Vec<(String, String, String)> {
+    let variants: BTreeMap<&str, &str> = entries
+        .iter()
+        .map(|(variant, file_name, _)| (file_name.as_str(), variant.as_str()))
+        .collect();
+    let variant = |label: &str| variants.get(label).map(|variant| variant.to_string());
+
+    let mut bases = BTreeMap::new();
+    for (name, file_name, _) in entries {
+        let pair = file_name
+            .strip_suffix("-line")
+            .or_else(|| file_name.strip_suffix("-fill"))
+            .and_then(|base| {
+                let line = variant(&format!("{}-line", base))?;
+                let fill = variant(&format!("{}-fill", base))?;
+                Some((base.to_string(), line, fill))
+            });
+        let (base_label, line, fill) =
+            pair.unwrap_or_else(|| (file_name.clone(), name.clone(), name.clone()));
+        let base = sanitize_identifier(&base_label);
+        let entry = (base.clone(), line.clone(), fill.clone());
+        if let Some(existing) = bases.insert(base_label.clone(), entry.clone())
+            && existing != entry
+        {
+            panic!(
+                "base name `{}` of `{}` collides with another icon",
+                base, file_name
+            );
+        }
+    }
+    bases.into_values().collect()
+}
+
 fn main() {
     let out_dir = env::var("OUT_DIR").unwrap();
     let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
     let icons_dir = Path::new(&manifest_dir).join("assets/icons");
 
+    println!("cargo:rerun-if-changed=build");
     println!("cargo:rerun-if-changed=assets/icons");
+    println!("cargo:rerun-if-changed=assets/icon-changes.txt");
+    println!("cargo:rerun-if-changed=assets/logo-services.txt");
+    println!("cargo:rerun-if-changed=Cargo.toml");
 
     // Collect all icons: (category_dir_name, category_snake, category_camel) -> [(variant_name, file_name, asset_path)]
-    let mut icons: BTreeMap<(String, String, String), Vec<(String, String, String)>> =
-        BTreeMap::new();
+    let mut icons: BTreeMap<Category, Vec<IconEntry>> = BTreeMap::new();
+
+    let mut svg_errors = Vec::new();
 
     if let Ok(categories) = fs::read_dir(&icons_dir) {
         for category_entry in categories.flatten() {
@@ -54,6 +198,13 @@ fn main() {
                             let file_name =
                                 file_path.file_stem().unwrap().to_string_lossy().to_string();
 
+                            let file =
+                                format!("assets/icons/{}/{}.svg", category_dir_name, file_name);
+                            match fs::read_to_string(&file_path) {
+                                Ok(content) => svg_errors.extend(validate_svg(&file, &content)),
+                                Err(err) => svg_errors.push(format!("{}: {}", file, err)),
+                            }
+
                             // Convert file name to enum variant name (sanitized)
                             let variant_name = sanitize_identifier(&file_name);
                             // Asset path for GPUI (relative to assets root)
@@ -75,33 +226,46 @@ fn main() {
         }
     }
 
-    // Sort icons within each category
+    if !svg_errors.is_empty() {
+        svg_errors.sort_by(|a, b| a.split(':').next().cmp(&b.split(':').next()));
+        panic!(
+            "{} invalid SVG icon(s):\n{}",
+            svg_errors.len(),
+            svg_errors.join("\n")
+        );
+    }
+
+    // Sort icons within each category by label, so label lookups can binary search
     for entries in icons.values_mut() {
-        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries.sort_by(|a, b| a.1.cmp(&b.1));
     }
 
+    // Resolve deprecated aliases for icons renamed upstream
+    let changes = read_icon_changes(&Path::new(&manifest_dir).join("assets/icon-changes.txt"));
+    let aliases = resolve_renames(&changes, |icon| {
+        let (category, label) = icon.split_once('/').unwrap();
+        icons.iter().any(|((_, snake, _), entries)| {
+            snake == category && entries.iter().any(|e| e.1 == label)
+        })
+    });
+
     // Generate the icon_name module
     let mut code = String::new();
     code.push_str("// This file is auto-generated by build.rs. Do not edit manually.\n\n");
 
     // Generate category modules with their icons
-    for ((_, category_snake, category_camel), entries) in &icons {
+    for ((category_dir_name, category_snake, category_camel), entries) in &icons {
         code.push_str(&format!("#[cfg(feature = \"{}\")]\n", category_snake));
         code.push_str(&format!("pub mod {} {{\n", category_snake));
         code.push_str(&format!("    //! {} icons.\n", category_camel));
         code.push_str("    //!\n");
-        code.push_str("    //! RemixIcon label → Rust variant\n");
-        code.push_str("    //!\n");
-        code.push_str("    //! | label | variant |\n");
-        code.push_str("    //! |---|---|\n");
-        for (variant, file_name, _) in entries {
-            code.push_str(&format!(
-                "    //! | `{}` | [`Icon::{}`] |\n",
-                file_name, variant
-            ));
-        }
+        code.push_str("    //! Each [`Icon`] variant documents its RemixIcon label.\n");
         code.push_str("    use super::*;\n\n");
         code.push_str("    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+        // `repr` is rejected on an enum without variants
+        if !entries.is_empty() {
+            code.push_str("    #[repr(u16)]\n");
+        }
         code.push_str("    pub enum Icon {\n");
         for (variant, file_name, _) in entries {
             code.push_str(&format!("        /// RemixIcon label: `{}`\n", file_name));
@@ -109,16 +273,221 @@ fn main() {
         }
         code.push_str("    }\n\n");
 
-        code.push_str("    impl crate::RemixIcon for Icon {\n");
-        code.push_str("        fn path(self) -> SharedString {\n");
-        code.push_str("            match self {\n");
-        for (variant, _, asset_path) in entries {
+        code.push_str("    impl Icon {\n");
+        code.push_str(
+            "        /// Name of the category module and Cargo feature this icon belongs to.\n",
+        );
+        code.push_str(&format!(
+            "        pub const CATEGORY: &'static str = \"{}\";\n\n",
+            category_snake
+        ));
+        code.push_str("        /// Every icon in this category, sorted by label.\n");
+        code.push_str("        pub const ALL: &'static [Icon] = &[\n");
+        for (variant, _, _) in entries {
+            code.push_str(&format!("            Self::{},\n", variant));
+        }
+        code.push_str("        ];\n\n");
+        code.push_str(
+            "        /// Length of the `icons/<Category>/` prefix of every asset path.\n",
+        );
+        code.push_str(&format!(
+            "        const PATH_PREFIX_LEN: usize = {};\n\n",
+            format!("icons/{}/", category_dir_name).len()
+        ));
+        code.push_str(
+            "        /// Returns the asset path of this icon, e.g. `icons/Arrows/arrow-up-s-line.svg`.\n",
+        );
+        code.push_str("        pub const fn asset_path(self) -> &'static str {\n");
+        code.push_str("            ASSET_PATHS[self as usize]\n");
+        code.push_str("        }\n\n");
+        code.push_str(
+            "        /// Returns the RemixIcon label of this icon, e.g. `arrow-up-s-line`.\n",
+        );
+        code.push_str("        pub fn label(self) -> &'static str {\n");
+        code.push_str("            let path = self.asset_path();\n");
+        code.push_str("            &path[Self::PATH_PREFIX_LEN..path.len() - \".svg\".len()]\n");
+        code.push_str("        }\n\n");
+        code.push_str(
+            "        /// Returns a readable name of this icon for assistive technology, e.g. `arrow up s`.\n",
+        );
+        code.push_str("        pub const fn accessible_label(self) -> &'static str {\n");
+        code.push_str("            ACCESSIBLE_LABELS[self as usize]\n");
+        code.push_str("        }\n\n");
+        code.push_str("        /// Looks up an icon of this category by its RemixIcon label.\n");
+        code.push_str("        pub fn from_label(label: &str) -> Option<Self> {\n");
+        code.push_str("            Self::ALL\n");
+        code.push_str("                .binary_search_by(|icon| icon.label().cmp(label))\n");
+        code.push_str("                .ok()\n");
+        code.push_str("                .map(|index| Self::ALL[index])\n");
+        code.push_str("        }\n");
+        code.push_str("    }\n\n");
+
+        // Path table shared by every path and label accessor, indexed by discriminant
+        code.push_str("    /// Asset paths of every icon, indexed by discriminant.\n");
+        code.push_str(&format!(
+            "    static ASSET_PATHS: [&str; {}] = [\n",
+            entries.len()
+        ));
+        for (_, _, asset_path) in entries {
+            code.push_str(&format!("        \"{}\",\n", asset_path));
+        }
+        code.push_str("    ];\n\n");
+
+        code.push_str("    /// Accessible labels of every icon, indexed by discriminant.\n");
+        code.push_str(&format!(
+            "    static ACCESSIBLE_LABELS: [&str; {}] = [\n",
+            entries.len()
+        ));
+        for (_, file_name, _) in entries {
+            code.push_str(&format!("        \"{}\",\n", accessible_label(file_name)));
+        }
+        code.push_str("    ];\n\n");
+
+        // Style-agnostic names: one variant per outlined/filled pair, unpaired icons pass through
+        let bases = icon_bases(entries);
+        code.push_str(
+            "    /// Style-agnostic names of this category's icons, resolved to the outlined or filled\n",
+        );
+        code.push_str("    /// [`Icon`] by [`IconStyle`].\n");
+        code.push_str("    ///\n");
+        code.push_str(
+            "    /// Icons without both a `-line` and a `-fill` variant pass through unchanged.\n",
+        );
+        code.push_str("    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+        if !bases.is_empty() {
+            code.push_str("    #[repr(u16)]\n");
+        }
+        code.push_str("    pub enum IconBase {\n");
+        for (base, line, fill) in &bases {
+            if line == fill {
+                code.push_str(&format!("        /// [`Icon::{}`] in every style\n", line));
+            } else {
+                code.push_str(&format!(
+                    "        /// [`Icon::{}`] or [`Icon::{}`]\n",
+                    line, fill
+                ));
+            }
+            code.push_str(&format!("        {},\n", base));
+        }
+        code.push_str("    }\n\n");
+
+        code.push_str("    impl IconBase {\n");
+        code.push_str("        /// Every style-agnostic name in this category.\n");
+        code.push_str("        pub const ALL: &'static [IconBase] = &[\n");
+        for (base, _, _) in &bases {
+            code.push_str(&format!("            Self::{},\n", base));
+        }
+        code.push_str("        ];\n\n");
+        code.push_str("        /// Returns the icon drawn in `style`.\n");
+        code.push_str("        pub const fn resolve(self, style: crate::IconStyle) -> Icon {\n");
+        code.push_str("            STYLED_ICONS[self as usize][style as usize]\n");
+        code.push_str("        }\n");
+        code.push_str("    }\n\n");
+
+        code.push_str("    impl Icon {\n");
+        code.push_str("        /// Returns the style-agnostic name of this icon.\n");
+        code.push_str("        pub const fn base(self) -> IconBase {\n");
+        code.push_str("            BASES[self as usize]\n");
+        code.push_str("        }\n");
+        code.push_str("    }\n\n");
+
+        let base_of: BTreeMap<&str, &str> = bases
+            .iter()
+            .flat_map(|(base, line, fill)| {
+                [
+                    (line.as_str(), base.as_str()),
+                    (fill.as_str(), base.as_str()),
+                ]
+            })
+            .collect();
+        code.push_str("    /// Base name of every icon, indexed by discriminant.\n");
+        code.push_str(&format!(
+            "    static BASES: [IconBase; {}] = [\n",
+            entries.len()
+        ));
+        for (variant, _, _) in entries {
             code.push_str(&format!(
-                "                Self::{} => \"{}\".into(),\n",
-                variant, asset_path
+                "        IconBase::{},\n",
+                base_of[variant.as_str()]
             ));
         }
-        code.push_str("            }\n");
+        code.push_str("    ];\n\n");
+
+        code.push_str(
+            "    /// Outlined and filled icon of every base name, indexed by discriminant and style.\n",
+        );
+        code.push_str(&format!(
+            "    static STYLED_ICONS: [[Icon; 2]; {}] = [\n",
+            bases.len()
+        ));
+        for (_, line, fill) in &bases {
+            code.push_str(&format!("        [Icon::{}, Icon::{}],\n", line, fill));
+        }
+        code.push_str("    ];\n\n");
+
+        code.push_str("    impl crate::RemixIconBase for IconBase {\n");
+        code.push_str("        fn path(self, style: crate::IconStyle) -> SharedString {\n");
+        code.push_str("            SharedString::new_static(self.resolve(style).asset_path())\n");
+        code.push_str("        }\n");
+        code.push_str("    }\n\n");
+
+        // Serialize as the RemixIcon label when the serde feature is enabled
+        code.push_str("    #[cfg(feature = \"serde\")]\n");
+        code.push_str("    impl ::serde::Serialize for Icon {\n");
+        code.push_str("        fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {\n");
+        code.push_str("            serializer.serialize_str(self.label())\n");
+        code.push_str("        }\n");
+        code.push_str("    }\n\n");
+        code.push_str("    #[cfg(feature = \"serde\")]\n");
+        code.push_str("    impl<'de> ::serde::Deserialize<'de> for Icon {\n");
+        code.push_str("        fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {\n");
+        code.push_str("            crate::label::deserialize(deserializer, false)\n");
+        code.push_str("        }\n");
+        code.push_str("    }\n\n");
+        code.push_str("    #[cfg(feature = \"serde\")]\n");
+        code.push_str("    impl crate::label::Labeled for Icon {\n");
+        code.push_str("        const CATEGORY: Option<&'static str> = Some(Icon::CATEGORY);\n\n");
+        code.push_str("        fn label(self) -> &'static str {\n");
+        code.push_str("            Icon::label(self)\n");
+        code.push_str("        }\n\n");
+        code.push_str("        fn category(self) -> &'static str {\n");
+        code.push_str("            Icon::CATEGORY\n");
+        code.push_str("        }\n\n");
+        code.push_str("        fn from_label(label: &str) -> Option<Self> {\n");
+        code.push_str("            Icon::from_label(label)\n");
+        code.push_str("        }\n");
+        code.push_str("    }\n\n");
+
+        // Deprecated aliases for icons renamed upstream within this category
+        let category_aliases: Vec<_> = aliases
+            .iter()
+            .filter_map(|(old, (new, version))| {
+                let (old_category, old_label) = old.split_once('/').unwrap();
+                let (new_category, new_label) = new.split_once('/').unwrap();
+                (old_category == category_snake && new_category == category_snake)
+                    .then_some((old_label, new_label, version))
+            })
+            .collect();
+        if !category_aliases.is_empty() {
+            code.push_str("    #[allow(non_upper_case_globals)]\n");
+            code.push_str("    impl Icon {\n");
+            for (old_label, new_label, version) in category_aliases {
+                code.push_str(&format!(
+                    "        #[deprecated(note = \"renamed to `{}` in RemixIcon {}\")]\n",
+                    new_label, version
+                ));
+                code.push_str(&format!(
+                    "        pub const {}: Icon = Icon::{};\n",
+                    sanitize_identifier(old_label),
+                    sanitize_identifier(new_label)
+                ));
+            }
+            code.push_str("    }\n\n");
+        }
+
+        code.push_str("    impl crate::RemixIcon for Icon {\n");
+        code.push_str("        fn path(self) -> SharedString {\n");
+        code.push_str("            SharedString::new_static(self.asset_path())\n");
         code.push_str("        }\n");
         code.push_str("    }\n\n");
 
@@ -126,14 +495,7 @@ fn main() {
         code.push_str("    #[cfg(feature = \"gpui-component\")]\n");
         code.push_str("    impl gpui_component::IconNamed for Icon {\n");
         code.push_str("        fn path(self) -> SharedString {\n");
-        code.push_str("            match self {\n");
-        for (variant, _, asset_path) in entries {
-            code.push_str(&format!(
-                "                Self::{} => \"{}\".into(),\n",
-                variant, asset_path
-            ));
-        }
-        code.push_str("            }\n");
+        code.push_str("            SharedString::new_static(self.asset_path())\n");
         code.push_str("        }\n");
         code.push_str("    }\n\n");
 
@@ -148,19 +510,87 @@ fn main() {
         code.push_str("        }\n");
         code.push_str("    }\n\n");
 
+        // Compass directions of arrows, written by hand
+        if category_snake == "arrows" {
+            code.push_str("    pub use crate::compass::CompassDirection;\n\n");
+        }
+
+        // Condition mapping of weather icons, written by hand
+        if category_snake == "weather" {
+            code.push_str(
+                "    pub use crate::weather_condition::{WeatherCondition, for_condition};\n\n",
+            );
+        }
+
+        // Service lookups of brand logos
+        if category_snake == "logos" {
+            let (services, domains) = read_logo_services(
+                &Path::new(&manifest_dir).join("assets/logo-services.txt"),
+                entries,
+            );
+            code.push_str("    /// Service names and aliases of logos, normalized by `service_key` and sorted.\n");
+            code.push_str("    static SERVICES: &[(&str, Icon)] = &[\n");
+            for (service, variant) in &services {
+                code.push_str(&format!("        (\"{}\", Icon::{}),\n", service, variant));
+            }
+            code.push_str("    ];\n\n");
+            code.push_str("    /// Domains of logos, sorted.\n");
+            code.push_str("    static DOMAINS: &[(&str, Icon)] = &[\n");
+            for (domain, variant) in &domains {
+                code.push_str(&format!("        (\"{}\", Icon::{}),\n", domain, variant));
+            }
+            code.push_str("    ];\n\n");
+            code.push_str(
+                "    include!(concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/build/service_key.rs\"));\n\n",
+            );
+            code.push_str("    /// Returns the logo of a service by name or alias, ignoring case and punctuation,\n");
+            code.push_str("    /// e.g. `\"github\"`, `\"GitHub\"` or `\"gh\"`.\n");
+            code.push_str("    pub fn for_service(name: &str) -> Option<Icon> {\n");
+            code.push_str("        let key = service_key(name);\n");
+            code.push_str("        SERVICES\n");
+            code.push_str(
+                "            .binary_search_by_key(&key.as_str(), |(service, _)| service)\n",
+            );
+            code.push_str("            .ok()\n");
+            code.push_str("            .map(|index| SERVICES[index].1)\n");
+            code.push_str("    }\n\n");
+            code.push_str("    /// Returns the logo of the service hosting `url`, e.g. `\"https://gist.github.com/...\"`.\n");
+            code.push_str("    ///\n");
+            code.push_str("    /// Subdomains match their parent domain. The scheme is optional, and credentials, port,\n");
+            code.push_str("    /// path, query and fragment are ignored.\n");
+            code.push_str("    pub fn from_url(url: &str) -> Option<Icon> {\n");
+            code.push_str(
+                "        let rest = url.split_once(\"://\").map_or(url, |(_, rest)| rest);\n",
+            );
+            code.push_str(
+                "        let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();\n",
+            );
+            code.push_str("        let host = authority.rsplit('@').next().unwrap_or_default();\n");
+            code.push_str("        let host = host.split(':').next().unwrap_or_default();\n");
+            code.push_str(
+                "        let host = host.trim_end_matches('.').to_ascii_lowercase();\n\n",
+            );
+            code.push_str("        let mut domain = host.as_str();\n");
+            code.push_str("        loop {\n");
+            code.push_str("            if let Ok(index) = DOMAINS.binary_search_by_key(&domain, |(domain, _)| domain) {\n");
+            code.push_str("                return Some(DOMAINS[index].1);\n");
+            code.push_str("            }\n");
+            code.push_str("            domain = domain.split_once('.')?.1;\n");
+            code.push_str("        }\n");
+            code.push_str("    }\n\n");
+        }
+
         // Generate Assets struct for this category
         code.push_str(&format!(
             "    /// Assets for {} icons. Implements `AssetSource` for GPUI.\n",
             category_camel
         ));
-        code.push_str(&format!("    #[derive(rust_embed::RustEmbed)]\n"));
-        code.push_str(&format!("    #[folder = \"assets\"]\n"));
+        code.push_str("    #[derive(rust_embed::RustEmbed)]\n");
+        code.push_str("    #[folder = \"assets\"]\n");
+        // Derive the glob from the directory on disk, never from a transformed category name
         code.push_str(&format!(
             "    #[include = \"icons/{}/*.svg\"]\n",
-            category_camel
-                .replace("And", " & ")
-                .replace("Faces", " Faces")
-                .replace("Medical", " Medical")
+            category_dir_name
         ));
         code.push_str(&format!("    pub struct {}Assets;\n\n", category_camel));
 
@@ -186,37 +616,344 @@ fn main() {
     }
 
     // Generate a flat enum with all icons (prefixed with category)
+    //
+    // Discriminants encode the category index (position among all categories, enabled or not)
+    // above CATEGORY_SHIFT and the icon's discriminant within its category enum below it.
+    // Without any category feature `RemixIconName` has no variants, which `repr` rejects
+    let any_category = format!(
+        "any({})",
+        icons
+            .keys()
+            .map(|(_, category_snake, _)| format!("feature = \"{}\"", category_snake))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    code.push_str("/// Bit offset of the category index in `RemixIconName` discriminants.\n");
+    code.push_str(&format!(
+        "const CATEGORY_SHIFT: u16 = {};\n\n",
+        CATEGORY_SHIFT
+    ));
+    code.push_str("/// An icon category, one per category module.\n");
     code.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
-    code.push_str("pub enum RemixIconName {\n");
-    for ((_, category_snake, category_camel), entries) in &icons {
+    code.push_str("pub enum Category {\n");
+    for (category_dir_name, category_snake, category_camel) in icons.keys() {
         code.push_str(&format!("    #[cfg(feature = \"{}\")]\n", category_snake));
-        for (variant, file_name, _) in entries {
+        code.push_str(&format!(
+            "    /// `{}` icons ([`{}::Icon`])\n",
+            category_dir_name, category_snake
+        ));
+        code.push_str(&format!("    {},\n", category_camel));
+    }
+    code.push_str("}\n\n");
+
+    code.push_str("impl Category {\n");
+    code.push_str("    /// Every enabled category.\n");
+    code.push_str("    pub const ALL: &'static [Category] = &[\n");
+    for (_, category_snake, category_camel) in icons.keys() {
+        code.push_str(&format!(
+            "        #[cfg(feature = \"{}\")]\n",
+            category_snake
+        ));
+        code.push_str(&format!("        Self::{},\n", category_camel));
+    }
+    code.push_str("    ];\n\n");
+    code.push_str(
+        "    /// Returns the module and feature name of this category, e.g. `health_and_medical`.\n",
+    );
+    code.push_str("    pub const fn name(self) -> &'static str {\n");
+    code.push_str("        match self {\n");
+    for (_, category_snake, category_camel) in icons.keys() {
+        code.push_str(&format!(
+            "            #[cfg(feature = \"{}\")]\n",
+            category_snake
+        ));
+        code.push_str(&format!(
+            "            Self::{} => {}::Icon::CATEGORY,\n",
+            category_camel, category_snake
+        ));
+    }
+    code.push_str("        }\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+
+    code.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+    code.push_str(&format!("#[cfg_attr({}, repr(u16))]\n", any_category));
+    code.push_str("pub enum RemixIconName {\n");
+    for (category_index, ((_, category_snake, category_camel), entries)) in icons.iter().enumerate()
+    {
+        for (index, (variant, file_name, _)) in entries.iter().enumerate() {
+            assert!(
+                index < 1 << CATEGORY_SHIFT,
+                "too many icons in {}",
+                category_snake
+            );
+            code.push_str(&format!("    #[cfg(feature = \"{}\")]\n", category_snake));
             code.push_str(&format!(
                 "    /// RemixIcon label: `{}` (`{}::Icon::{}`)\n",
                 file_name, category_snake, variant
             ));
-            code.push_str(&format!("    {}{},\n", category_camel, variant));
+            code.push_str(&format!(
+                "    {}{} = {:#06x},\n",
+                category_camel,
+                variant,
+                (category_index << CATEGORY_SHIFT) | index
+            ));
         }
     }
     code.push_str("}\n\n");
 
-    code.push_str("impl crate::RemixIcon for RemixIconName {\n");
-    code.push_str("    fn path(self) -> SharedString {\n");
-    code.push_str("        match self {\n");
+    // Deprecated aliases for renamed icons, including those moved between categories
+    let category_camels: BTreeMap<&str, &str> = icons
+        .keys()
+        .map(|(_, snake, camel)| (snake.as_str(), camel.as_str()))
+        .collect();
+    let variant_name = |icon: &str| {
+        let (category, label) = icon.split_once('/').unwrap();
+        let camel = category_camels
+            .get(category)
+            .copied()
+            .map(str::to_string)
+            .unwrap_or_else(|| category_to_camel_case(category));
+        format!("{}{}", camel, sanitize_identifier(label))
+    };
+    if !aliases.is_empty() {
+        code.push_str("#[allow(non_upper_case_globals)]\n");
+        code.push_str("impl RemixIconName {\n");
+        for (old, (new, version)) in &aliases {
+            let (new_category, _) = new.split_once('/').unwrap();
+            code.push_str(&format!("    #[cfg(feature = \"{}\")]\n", new_category));
+            code.push_str(&format!(
+                "    #[deprecated(note = \"renamed to `{}` in RemixIcon {}\")]\n",
+                new, version
+            ));
+            code.push_str(&format!(
+                "    pub const {}: Self = Self::{};\n",
+                variant_name(old),
+                variant_name(new)
+            ));
+        }
+        code.push_str("}\n\n");
+    }
+
+    // Conversions from the category enums live next to RemixIconName rather than in the category
+    // modules, so the large enum's debuginfo is not repeated in every category's codegen unit
     for ((_, category_snake, category_camel), entries) in &icons {
+        code.push_str(&format!("#[cfg(feature = \"{}\")]\n", category_snake));
         code.push_str(&format!(
-            "            #[cfg(feature = \"{}\")]\n",
+            "impl From<{}::Icon> for RemixIconName {{\n",
             category_snake
         ));
-        for (variant, _, asset_path) in entries {
+        code.push_str(&format!(
+            "    fn from(icon: {}::Icon) -> Self {{\n",
+            category_snake
+        ));
+        code.push_str(&format!(
+            "        static NAMES: [RemixIconName; {}] = [\n",
+            entries.len()
+        ));
+        for (variant, _, _) in entries {
             code.push_str(&format!(
-                "            Self::{}{} => \"{}\".into(),\n",
-                category_camel, variant, asset_path
+                "            RemixIconName::{}{},\n",
+                category_camel, variant
             ));
         }
+        code.push_str("        ];\n");
+        code.push_str("        NAMES[icon as usize]\n");
+        code.push_str("    }\n");
+        code.push_str("}\n\n");
+
+        code.push_str(&format!("#[cfg(feature = \"{}\")]\n", category_snake));
+        code.push_str(&format!(
+            "impl TryFrom<RemixIconName> for {}::Icon {{\n",
+            category_snake
+        ));
+        code.push_str("    /// The name is handed back when it belongs to another category.\n");
+        code.push_str("    type Error = RemixIconName;\n\n");
+        code.push_str("    fn try_from(name: RemixIconName) -> Result<Self, Self::Error> {\n");
+        code.push_str("        match name.split() {\n");
+        code.push_str(&format!(
+            "            (Category::{}, index) => Ok(Self::ALL[index as usize]),\n",
+            category_camel
+        ));
+        code.push_str("            #[allow(unreachable_patterns)]\n");
+        code.push_str("            _ => Err(name),\n");
+        code.push_str("        }\n");
+        code.push_str("    }\n");
+        code.push_str("}\n\n");
     }
+
+    code.push_str("impl crate::RemixIcon for RemixIconName {\n");
+    code.push_str("    fn path(self) -> SharedString {\n");
+    code.push_str("        SharedString::new_static(self.asset_path())\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+
+    // Accessors delegate to the category enums, which own the tables
+    let dispatch = |code: &mut String, method: &str| {
+        code.push_str(&format!("        #[cfg(not({}))]\n", any_category));
+        code.push_str("        match self {}\n");
+        code.push_str(&format!("        #[cfg({})]\n", any_category));
+        code.push_str("        match self.split() {\n");
+        for (_, category_snake, category_camel) in icons.keys() {
+            code.push_str(&format!(
+                "            #[cfg(feature = \"{}\")]\n",
+                category_snake
+            ));
+            code.push_str(&format!(
+                "            (Category::{}, index) => {}::Icon::ALL[index as usize].{}(),\n",
+                category_camel, category_snake, method
+            ));
+        }
+        code.push_str("        }\n");
+    };
+    code.push_str("impl RemixIconName {\n");
+    code.push_str("    /// Returns the category of this icon.\n");
+    code.push_str("    pub const fn category(self) -> Category {\n");
+    code.push_str(&format!("        #[cfg(not({}))]\n", any_category));
+    code.push_str("        match self {}\n");
+    code.push_str(&format!("        #[cfg({})]\n", any_category));
+    code.push_str("        match self as u16 >> CATEGORY_SHIFT {\n");
+    for (category_index, (_, category_snake, category_camel)) in icons.keys().enumerate() {
+        code.push_str(&format!(
+            "            #[cfg(feature = \"{}\")]\n",
+            category_snake
+        ));
+        code.push_str(&format!(
+            "            {} => Category::{},\n",
+            category_index, category_camel
+        ));
+    }
+    code.push_str("            _ => unreachable!(),\n");
     code.push_str("        }\n");
+    code.push_str("    }\n\n");
+    code.push_str(
+        "    /// Splits this icon into its category and its discriminant within that category's\n",
+    );
+    code.push_str("    /// `Icon` enum (an index into its `Icon::ALL`).\n");
+    code.push_str("    pub const fn split(self) -> (Category, u16) {\n");
+    code.push_str("        (self.category(), self as u16 & ((1 << CATEGORY_SHIFT) - 1))\n");
+    code.push_str("    }\n\n");
+    code.push_str(
+        "    /// Returns the asset path of this icon, e.g. `icons/Arrows/arrow-up-s-line.svg`.\n",
+    );
+    code.push_str("    pub const fn asset_path(self) -> &'static str {\n");
+    dispatch(&mut code, "asset_path");
+    code.push_str("    }\n\n");
+    code.push_str("    /// Returns the RemixIcon label of this icon, e.g. `arrow-up-s-line`.\n");
+    code.push_str("    pub fn label(self) -> &'static str {\n");
+    dispatch(&mut code, "label");
+    code.push_str("    }\n\n");
+    code.push_str(
+        "    /// Returns a readable name of this icon for assistive technology, e.g. `arrow up s`.\n",
+    );
+    code.push_str("    pub const fn accessible_label(self) -> &'static str {\n");
+    dispatch(&mut code, "accessible_label");
+    code.push_str("    }\n\n");
+    code.push_str("    /// Looks up an icon of any enabled category by its RemixIcon label.\n");
+    code.push_str("    pub fn from_label(label: &str) -> Option<Self> {\n");
+    for (_, category_snake, _) in icons.keys() {
+        code.push_str(&format!(
+            "        #[cfg(feature = \"{}\")]\n",
+            category_snake
+        ));
+        code.push_str(&format!(
+            "        if let Some(icon) = {}::Icon::from_label(label) {{\n",
+            category_snake
+        ));
+        code.push_str("            return Some(Self::from(icon));\n");
+        code.push_str("        }\n");
+    }
+    // Keeps `label` used when no category is enabled
+    code.push_str("        let _ = label;\n");
+    code.push_str("        None\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+
+    code.push_str("#[cfg(feature = \"serde\")]\n");
+    code.push_str("impl ::serde::Serialize for RemixIconName {\n");
+    code.push_str("    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {\n");
+    code.push_str("        serializer.serialize_str(self.label())\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("#[cfg(feature = \"serde\")]\n");
+    code.push_str("impl<'de> ::serde::Deserialize<'de> for RemixIconName {\n");
+    code.push_str("    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {\n");
+    code.push_str("        crate::label::deserialize(deserializer, false)\n");
     code.push_str("    }\n");
+    code.push_str("}\n\n");
+    code.push_str("#[cfg(feature = \"serde\")]\n");
+    code.push_str("impl crate::label::Labeled for RemixIconName {\n");
+    code.push_str("    const CATEGORY: Option<&'static str> = None;\n\n");
+    code.push_str("    fn label(self) -> &'static str {\n");
+    code.push_str("        RemixIconName::label(self)\n");
+    code.push_str("    }\n\n");
+    code.push_str("    fn category(self) -> &'static str {\n");
+    code.push_str(&format!("        #[cfg(not({}))]\n", any_category));
+    code.push_str("        match self {}\n");
+    code.push_str(&format!("        #[cfg({})]\n", any_category));
+    code.push_str("        RemixIconName::category(self).name()\n");
+    code.push_str("    }\n\n");
+    code.push_str("    fn from_label(label: &str) -> Option<Self> {\n");
+    code.push_str("        RemixIconName::from_label(label)\n");
+    code.push_str("    }\n");
+    code.push_str("}\n\n");
+
+    // Labels of every category, regardless of enabled features, so deserialization can name
+    // the feature that is missing
+    code.push_str(
+        "/// RemixIcon labels of every category (including disabled ones), sorted by label.\n",
+    );
+    code.push_str("#[cfg(feature = \"serde\")]\n");
+    code.push_str("pub(crate) static CATEGORY_LABELS: &[(&str, &[&str])] = &[\n");
+    for ((_, category_snake, _), entries) in &icons {
+        let mut labels: Vec<&str> = entries
+            .iter()
+            .map(|(_, file_name, _)| file_name.as_str())
+            .collect();
+        labels.sort_unstable();
+        code.push_str(&format!("    (\"{}\", &[\n", category_snake));
+        for label in labels {
+            code.push_str(&format!("        \"{}\",\n", label));
+        }
+        code.push_str("    ]),\n");
+    }
+    code.push_str("];\n");
+
+    // Generate the changelog of upstream icon changes, newest version first
+    let version = upstream_version(&Path::new(&manifest_dir).join("Cargo.toml"));
+    let mut versions: Vec<&str> = Vec::new();
+    for (version, _) in changes.iter().rev() {
+        if !versions.contains(&version.as_str()) {
+            versions.push(version);
+        }
+    }
+    code.push_str("\npub mod changelog {\n");
+    code.push_str("    //! Changes to the RemixIcon set across upstream versions.\n");
+    code.push_str("    //!\n");
+    code.push_str(&format!(
+        "    //! This crate embeds RemixIcon {}. Icons renamed upstream keep a deprecated alias\n",
+        version
+    ));
+    code.push_str("    //! under their old name that points to the new one.\n");
+    if versions.is_empty() {
+        code.push_str("    //!\n");
+        code.push_str("    //! No changes recorded.\n");
+    }
+    for version in versions {
+        code.push_str("    //!\n");
+        code.push_str(&format!("    //! ## {}\n", version));
+        code.push_str("    //!\n");
+        code.push_str("    //! | change | icon |\n");
+        code.push_str("    //! |---|---|\n");
+        for (_, change) in changes.iter().filter(|(v, _)| v == version) {
+            let row = match change {
+                IconChange::Added(icon) => format!("added | `{}`", icon),
+                IconChange::Removed(icon) => format!("removed | `{}`", icon),
+                IconChange::Renamed(old, new) => format!("renamed | `{}` → `{}`", old, new),
+            };
+            code.push_str(&format!("    //! | {} |\n", row));
+        }
+    }
     code.push_str("}\n");
 
     // Generate the assets module
@@ -238,7 +975,7 @@ fn main() {
     assets_code.push_str("#[folder = \"assets\"]\n");
 
     // Add include patterns for all categories based on features
-    for ((category_dir_name, category_snake, _), _) in &icons {
+    for (category_dir_name, category_snake, _) in icons.keys() {
         assets_code.push_str(&format!(
             "#[cfg_attr(feature = \"{}\", include = \"icons/{}/*.svg\")]\n",
             category_snake, category_dir_name
@@ -263,10 +1000,76 @@ fn main() {
     assets_code.push_str("    }\n");
     assets_code.push_str("}\n");
 
+    // Generate tests checking that every icon resolves to an embedded asset, included by
+    // tests/assets.rs
+    let mut tests_code = String::new();
+    tests_code.push_str("// This file is auto-generated by build.rs. Do not edit manually.\n");
+    for (category_dir_name, category_snake, category_camel) in icons.keys() {
+        tests_code.push_str(&format!("\n#[cfg(feature = \"{}\")]\n", category_snake));
+        tests_code.push_str("#[test]\n");
+        tests_code.push_str(&format!("fn {}_icons_resolve() {{\n", category_snake));
+        tests_code.push_str(&format!(
+            "    use gpui_remixicon::{}::{{{}Assets, Icon}};\n\n",
+            category_snake, category_camel
+        ));
+        tests_code.push_str("    for &icon in Icon::ALL {\n");
+        tests_code.push_str(&format!(
+            "        assert_resolves(icon, icon.label(), &{}Assets, \"icons/{}/\");\n",
+            category_camel, category_dir_name
+        ));
+        tests_code
+            .push_str("        assert!(!icon.accessible_label().is_empty(), \"{icon:?}\");\n");
+        tests_code.push_str(
+            "        assert!(\n            [gpui_remixicon::IconStyle::Line, gpui_remixicon::IconStyle::Fill]\n                .into_iter()\n                .any(|style| icon.base().resolve(style) == icon),\n            \"{icon:?}\"\n        );\n",
+        );
+        tests_code.push_str(&format!(
+            "        assert_eq!(\n            gpui_remixicon::RemixIconName::from(icon).split(),\n            (gpui_remixicon::Category::{}, icon as u16)\n        );\n",
+            category_camel
+        ));
+        tests_code.push_str("    }\n");
+        tests_code.push_str(&format!(
+            "    assert_lists_only(&{}Assets, \"icons/{}/\", Icon::ALL.len());\n",
+            category_camel, category_dir_name
+        ));
+        tests_code.push_str("}\n");
+    }
+
+    // Every deprecated alias must resolve to the icon it was renamed to
+    for (old, (new, _)) in &aliases {
+        let (old_category, old_label) = old.split_once('/').unwrap();
+        let (new_category, new_label) = new.split_once('/').unwrap();
+        tests_code.push_str(&format!("\n#[cfg(feature = \"{}\")]\n", new_category));
+        tests_code.push_str("#[test]\n");
+        tests_code.push_str("#[allow(deprecated)]\n");
+        tests_code.push_str(&format!(
+            "fn renamed_{}_{}_resolves() {{\n",
+            old_category,
+            old_label.replace('-', "_")
+        ));
+        tests_code.push_str(&format!(
+            "    assert_eq!(\n        gpui_remixicon::RemixIconName::{},\n        gpui_remixicon::RemixIconName::{}\n    );\n",
+            variant_name(old),
+            variant_name(new)
+        ));
+        if old_category == new_category {
+            tests_code.push_str(&format!(
+                "    assert_eq!(\n        gpui_remixicon::{}::Icon::{},\n        gpui_remixicon::{}::Icon::{}\n    );\n",
+                new_category,
+                sanitize_identifier(old_label),
+                new_category,
+                sanitize_identifier(new_label)
+            ));
+        }
+        tests_code.push_str("}\n");
+    }
+
     // Write the generated code
     let dest_path = Path::new(&out_dir).join("icon_name.rs");
     fs::write(&dest_path, code).unwrap();
 
     let assets_path = Path::new(&out_dir).join("assets.rs");
     fs::write(&assets_path, assets_code).unwrap();
+
+    let tests_path = Path::new(&out_dir).join("asset_tests.rs");
+    fs::write(&tests_path, tests_code).unwrap();
 }
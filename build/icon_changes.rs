@@ -0,0 +1,105 @@
+//! The upstream change table `assets/icon-changes.txt`, shared by `build.rs`, which turns it
+//! into deprecated aliases and the `changelog` module, and `cargo xtask sync-icons`, which
+//! appends to it.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// A change to the upstream icon set, as recorded in `assets/icon-changes.txt`.
+#[derive(Debug, PartialEq)]
+pub enum IconChange {
+    Added(String),
+    Removed(String),
+    Renamed(String, String),
+}
+
+/// Parse `assets/icon-changes.txt` into `(version, change)` pairs, in file order.
+///
+/// Each non-comment line is `<version> added|removed <category>/<label>` or
+/// `<version> renamed <category>/<old-label> <category>/<new-label>`.
+pub fn read_icon_changes(path: &Path) -> Vec<(String, IconChange)> {
+    let Ok(content) = fs::read_to_string(path) else {
+        return Vec::new();
+    };
+
+    let mut changes = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let change = match fields.as_slice() {
+            [_, "added", icon] => IconChange::Added(icon.to_string()),
+            [_, "removed", icon] => IconChange::Removed(icon.to_string()),
+            [_, "renamed", old, new] => IconChange::Renamed(old.to_string(), new.to_string()),
+            _ => panic!(
+                "{}:{}: expected `<version> added|removed <icon>` or `<version> renamed <old> <new>`",
+                path.display(),
+                index + 1
+            ),
+        };
+        for icon in fields[2..].iter() {
+            if !icon.contains('/') {
+                panic!(
+                    "{}:{}: `{}` must be qualified as `<category>/<label>`",
+                    path.display(),
+                    index + 1,
+                    icon
+                );
+            }
+        }
+        changes.push((fields[0].to_string(), change));
+    }
+    changes
+}
+
+/// Resolve renames into deprecated aliases: `old icon -> (current icon, version of the rename)`.
+///
+/// Chains of renames are followed to the icon's current name. Renames whose final target was
+/// removed upstream produce no alias. Panics if an icon is renamed twice, if renames form a
+/// cycle, or if a rename leads to an icon that neither exists nor was removed.
+pub fn resolve_renames(
+    changes: &[(String, IconChange)],
+    exists: impl Fn(&str) -> bool,
+) -> BTreeMap<String, (String, String)> {
+    let mut renames: BTreeMap<&str, (&str, &str)> = BTreeMap::new();
+    for (version, change) in changes {
+        if let IconChange::Renamed(old, new) = change
+            && renames
+                .insert(old.as_str(), (new.as_str(), version.as_str()))
+                .is_some()
+        {
+            panic!("icon-changes.txt: `{}` is renamed more than once", old);
+        }
+    }
+
+    let mut aliases = BTreeMap::new();
+    for (old, (new, version)) in &renames {
+        if exists(old) {
+            panic!("icon-changes.txt: `{}` was renamed but still exists", old);
+        }
+        let mut target = *new;
+        let mut steps = 0;
+        while let Some((next, _)) = renames.get(target) {
+            target = next;
+            steps += 1;
+            if steps > renames.len() {
+                panic!("icon-changes.txt: rename cycle involving `{}`", old);
+            }
+        }
+        if exists(target) {
+            aliases.insert(old.to_string(), (target.to_string(), version.to_string()));
+        } else if !changes
+            .iter()
+            .any(|(_, change)| matches!(change, IconChange::Removed(icon) if icon == target))
+        {
+            panic!(
+                "icon-changes.txt: `{}` was renamed to `{}`, which does not exist",
+                old, target
+            );
+        }
+    }
+    aliases
+}
@@ -0,0 +1,10 @@
+// Shared by build.rs, which sorts the `logos` service table, and the generated `logos` module,
+// which looks names up in it, so both normalize names the same way.
+
+/// Normalize a service name for lookups: ASCII letters and digits only, lowercase.
+pub(crate) fn service_key(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
@@ -0,0 +1,146 @@
+//! Rules every embedded icon SVG must follow, shared by `build.rs` and `cargo xtask sync-icons`.
+
+/// Icons drawn upstream on a grid other than 24x24, accepted as-is.
+const VIEWBOX_EXCEPTIONS: &[&str] = &["Design/color-filter-ai-fill.svg"];
+
+/// Elements that can run code or embed foreign content.
+const FORBIDDEN_ELEMENTS: &[&str] = &["script", "foreignObject", "iframe", "audio", "video"];
+
+/// Check that an icon renders correctly under `Icon::text_color` and is safe to embed.
+///
+/// Returns one `file:line:column: message` diagnostic per problem found.
+pub fn validate_svg(file: &str, content: &str) -> Vec<String> {
+    let doc = match roxmltree::Document::parse(content) {
+        Ok(doc) => doc,
+        Err(err) => {
+            let pos = err.pos();
+            return vec![format!(
+                "{}:{}:{}: invalid SVG: {}",
+                file, pos.row, pos.col, err
+            )];
+        }
+    };
+
+    let mut errors = Vec::new();
+    let mut error_at = |offset: usize, message: String| {
+        let pos = doc.text_pos_at(offset);
+        errors.push(format!("{}:{}:{}: {}", file, pos.row, pos.col, message));
+    };
+
+    let root = doc.root_element();
+    if root.tag_name().name() != "svg" {
+        error_at(
+            root.range().start,
+            format!(
+                "root element is <{}>, expected <svg>",
+                root.tag_name().name()
+            ),
+        );
+    }
+    let view_box = root.attribute("viewBox").map(|v| {
+        v.split([' ', ','])
+            .filter(|n| !n.is_empty())
+            .collect::<Vec<_>>()
+    });
+    if view_box.as_deref() != Some(&["0", "0", "24", "24"])
+        && !VIEWBOX_EXCEPTIONS
+            .iter()
+            .any(|exception| file.ends_with(exception))
+    {
+        error_at(
+            root.range().start,
+            format!(
+                "viewBox is {:?}, expected \"0 0 24 24\"",
+                root.attribute("viewBox").unwrap_or_default()
+            ),
+        );
+    }
+
+    let mut uses_current_color = false;
+    for node in root.descendants().filter(|n| n.is_element()) {
+        let name = node.tag_name().name();
+        if FORBIDDEN_ELEMENTS.contains(&name) {
+            error_at(node.range().start, format!("<{}> is not allowed", name));
+        }
+        for attr in node.attributes() {
+            let value = attr.value();
+            match attr.name() {
+                "fill" | "stroke" | "color" => {
+                    if value == "currentColor" {
+                        uses_current_color = true;
+                    } else if value != "none" {
+                        error_at(
+                            attr.range().start,
+                            format!("hard-coded {}=\"{}\", use currentColor", attr.name(), value),
+                        );
+                    }
+                }
+                "style" if value.contains("fill:") || value.contains("stroke:") => {
+                    error_at(
+                        attr.range().start,
+                        "colors must be attributes, not inline styles".into(),
+                    );
+                }
+                "href" if !value.starts_with('#') => {
+                    error_at(
+                        attr.range().start,
+                        format!("external reference \"{}\"", value),
+                    );
+                }
+                name if name.starts_with("on") => {
+                    error_at(
+                        attr.range().start,
+                        format!("event handler `{}` is not allowed", name),
+                    );
+                }
+                _ if value.contains("url(") && !value.contains("url(#") => {
+                    error_at(
+                        attr.range().start,
+                        format!("external reference in {}=\"{}\"", attr.name(), value),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+    if !uses_current_color {
+        error_at(
+            root.range().start,
+            "no fill or stroke uses currentColor".into(),
+        );
+    }
+
+    errors
+}
+
+/// Remove hard-coded `fill` colors below a root that fills with `currentColor`.
+///
+/// A few upstream icons repeat a dark brand color on their paths, which would override
+/// `Icon::text_color`; everything else in the document is kept byte for byte.
+#[allow(dead_code)] // Only `cargo xtask sync-icons` rewrites icons
+pub fn strip_hard_coded_fills(content: &str) -> String {
+    let Ok(doc) = roxmltree::Document::parse(content) else {
+        return content.to_string();
+    };
+    let root = doc.root_element();
+    if root.attribute("fill") != Some("currentColor") {
+        return content.to_string();
+    }
+
+    let mut stripped = content.to_string();
+    let mut ranges: Vec<_> = root
+        .descendants()
+        .skip(1)
+        .filter(|node| node.is_element())
+        .flat_map(|node| node.attributes())
+        .filter(|attr| attr.name() == "fill" && !matches!(attr.value(), "currentColor" | "none"))
+        .map(|attr| attr.range())
+        .collect();
+    // Remove back to front so earlier ranges stay valid, along with the preceding whitespace
+    ranges.sort_by_key(|range| std::cmp::Reverse(range.start));
+    for range in ranges {
+        let start = stripped[..range.start].trim_end().len();
+        stripped.replace_range(start..range.end, "");
+    }
+    stripped
+}
@@ -85,7 +85,7 @@ fn main() {
                 ))),
                 ..Default::default()
             },
-            |_window, cx| cx.new(|cx| GpuiIconExample::new(cx)),
+            |_window, cx| cx.new(GpuiIconExample::new),
         )
         .unwrap();
     });
@@ -0,0 +1,249 @@
+//! Clickable icons for apps that don't use gpui-component.
+
+use crate::{Icon, IconRole, IconStyle, IconTheme, RemixIconBase, Sizable, Size};
+use gpui::{
+    AnyView, App, AppContext as _, ClickEvent, Context, ElementId, InteractiveElement as _,
+    IntoElement, ParentElement as _, Render, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled, Window, div, prelude::FluentBuilder as _,
+};
+use std::rc::Rc;
+
+type ClickHandler = Rc<dyn Fn(&ClickEvent, &mut Window, &mut App)>;
+type ToggleHandler = Rc<dyn Fn(&bool, &mut Window, &mut App)>;
+
+/// A clickable icon.
+///
+/// The button is focusable with Tab and activates on click, Enter or Space. Its tooltip shows the
+/// [`tooltip`](Self::tooltip) text, else the icon's [accessible label](Icon::accessible_label).
+/// Hover, press and focus feedback use the [`IconTheme`] colors.
+///
+/// ```
+/// use gpui_remixicon::{IconButton, system};
+///
+/// let delete = IconButton::new("delete", system::Icon::DeleteBinLine)
+///     .tooltip("Delete file")
+///     .on_click(|_, _, _| println!("deleted"));
+/// ```
+#[derive(IntoElement)]
+pub struct IconButton {
+    id: ElementId,
+    icon: Icon,
+    /// Tooltip text, resolved from the icon when the button is created.
+    label: Option<SharedString>,
+    disabled: bool,
+    on_click: Option<ClickHandler>,
+}
+
+impl IconButton {
+    /// Create a button drawing `icon`.
+    pub fn new(id: impl Into<ElementId>, icon: impl Into<Icon>) -> Self {
+        let icon = icon.into();
+        Self {
+            id: id.into(),
+            label: icon.accessible_label(),
+            icon,
+            disabled: false,
+            on_click: None,
+        }
+    }
+
+    /// Set the tooltip text, replacing the icon's accessible label.
+    pub fn tooltip(mut self, text: impl Into<SharedString>) -> Self {
+        self.label = Some(text.into());
+        self
+    }
+
+    /// Disable the button: it is dimmed, skipped by Tab and ignores clicks.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Whether the button is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Call `handler` when the button is clicked or activated from the keyboard.
+    pub fn on_click(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Sizable for IconButton {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.icon = self.icon.with_size(size);
+        self
+    }
+}
+
+impl RenderOnce for IconButton {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let muted = IconTheme::resolve(IconRole::Muted, cx);
+        let accent = IconTheme::resolve(IconRole::Accent, cx);
+        let mut icon = self.icon;
+        if self.disabled && icon.text_color.is_none() {
+            icon.role = Some(IconRole::Muted);
+        }
+
+        div()
+            .id(self.id)
+            .flex()
+            .flex_none()
+            .items_center()
+            .justify_center()
+            .p_1()
+            .rounded_md()
+            .border_1()
+            .border_color(gpui::transparent_black())
+            .when(self.disabled, |this| this.opacity(0.5))
+            .when(!self.disabled, |this| {
+                this.tab_index(0)
+                    .cursor_pointer()
+                    .hover(|style| style.bg(muted.opacity(0.15)))
+                    .active(|style| style.bg(muted.opacity(0.3)))
+                    .focus(|style| style.border_color(accent))
+                    .when_some(self.on_click, |this, on_click| {
+                        this.on_click(move |event, window, cx| on_click(event, window, cx))
+                    })
+            })
+            .when_some(self.label, |this, text| {
+                this.tooltip(move |_, cx| IconTooltip::view(text.clone(), cx))
+            })
+            .child(icon)
+    }
+}
+
+/// An icon button switching between the outlined and filled variant of an icon, e.g. for
+/// "favorite" or "pin" toggles.
+///
+/// The icon is drawn filled and in the [`IconRole::Accent`] color while selected. Apart from
+/// that, it behaves like an [`IconButton`].
+///
+/// ```
+/// use gpui_remixicon::{IconToggle, system};
+///
+/// let starred = true;
+/// let star = IconToggle::new("star", system::IconBase::Star)
+///     .selected(starred)
+///     .on_toggle(|selected, _, _| println!("starred: {selected}"));
+/// ```
+#[derive(IntoElement)]
+pub struct IconToggle {
+    id: ElementId,
+    icon: Icon,
+    selected: bool,
+    tooltip: Option<SharedString>,
+    disabled: bool,
+    on_toggle: Option<ToggleHandler>,
+}
+
+impl IconToggle {
+    /// Create a toggle drawing the outlined variant of `icon`, or the filled one while selected.
+    pub fn new(id: impl Into<ElementId>, icon: impl RemixIconBase + Copy) -> Self {
+        Self {
+            id: id.into(),
+            icon: Icon::named(icon),
+            selected: false,
+            tooltip: None,
+            disabled: false,
+            on_toggle: None,
+        }
+    }
+
+    /// Set whether the toggle is on.
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Whether the toggle is on.
+    pub fn is_selected(&self) -> bool {
+        self.selected
+    }
+
+    /// Set the tooltip text, replacing the icon's accessible label.
+    pub fn tooltip(mut self, text: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(text.into());
+        self
+    }
+
+    /// Disable the toggle: it is dimmed, skipped by Tab and ignores clicks.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Call `handler` with the new state when the toggle is clicked or activated from the
+    /// keyboard. The toggle doesn't store its state: pass it back through
+    /// [`selected`](Self::selected).
+    pub fn on_toggle(mut self, handler: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_toggle = Some(Rc::new(handler));
+        self
+    }
+
+    /// The icon drawn in the current state.
+    pub fn icon(&self) -> Icon {
+        let style = if self.selected {
+            IconStyle::Fill
+        } else {
+            IconStyle::Line
+        };
+        let mut icon = self.icon.clone().styled(style);
+        if self.selected && icon.role.is_none() {
+            icon.role = Some(IconRole::Accent);
+        }
+        icon
+    }
+}
+
+impl Sizable for IconToggle {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.icon = self.icon.with_size(size);
+        self
+    }
+}
+
+impl RenderOnce for IconToggle {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let selected = self.selected;
+        let icon = self.icon();
+        let mut button = IconButton {
+            id: self.id,
+            label: self.tooltip.or_else(|| icon.accessible_label()),
+            icon,
+            disabled: self.disabled,
+            on_click: None,
+        };
+        if let Some(on_toggle) = self.on_toggle {
+            button = button.on_click(move |_, window, cx| on_toggle(&!selected, window, cx));
+        }
+        button
+    }
+}
+
+/// The tooltip of icon buttons.
+struct IconTooltip(SharedString);
+
+impl IconTooltip {
+    fn view(text: SharedString, cx: &mut App) -> AnyView {
+        cx.new(|_| Self(text)).into()
+    }
+}
+
+impl Render for IconTooltip {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .px_2()
+            .py_1()
+            .rounded_md()
+            .bg(gpui::black().opacity(0.85))
+            .text_color(gpui::white())
+            .text_xs()
+            .child(self.0.clone())
+    }
+}
@@ -0,0 +1,73 @@
+//! Arrows for compass directions, re-exported from the `arrows` module.
+
+use crate::arrows::Icon;
+
+/// One of the eight principal directions of a compass, e.g. a heading or a wind direction.
+///
+/// Directions map to `arrows` icons: the compass icons of the `map` category draw a compass
+/// without a heading.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompassDirection {
+    /// North, up.
+    North,
+    /// North-east.
+    NorthEast,
+    /// East, right.
+    East,
+    /// South-east.
+    SouthEast,
+    /// South, down.
+    South,
+    /// South-west.
+    SouthWest,
+    /// West, left.
+    West,
+    /// North-west.
+    NorthWest,
+}
+
+impl CompassDirection {
+    /// Every direction, clockwise from north.
+    pub const ALL: [CompassDirection; 8] = [
+        CompassDirection::North,
+        CompassDirection::NorthEast,
+        CompassDirection::East,
+        CompassDirection::SouthEast,
+        CompassDirection::South,
+        CompassDirection::SouthWest,
+        CompassDirection::West,
+        CompassDirection::NorthWest,
+    ];
+
+    /// Returns the direction nearest to a bearing in degrees clockwise from north, e.g. `90.` for
+    /// east. Bearings outside `0..360` wrap around.
+    pub fn from_degrees(degrees: f32) -> Self {
+        let sector = ((degrees.rem_euclid(360.) + 22.5) / 45.) as usize;
+        Self::ALL[sector % Self::ALL.len()]
+    }
+
+    /// Returns the bearing of the direction in degrees clockwise from north.
+    pub fn degrees(self) -> f32 {
+        self as usize as f32 * 45.
+    }
+
+    /// Returns the arrow pointing in this direction, north being up.
+    pub fn icon(self) -> Icon {
+        match self {
+            CompassDirection::North => Icon::ArrowUpLine,
+            CompassDirection::NorthEast => Icon::ArrowRightUpLine,
+            CompassDirection::East => Icon::ArrowRightLine,
+            CompassDirection::SouthEast => Icon::ArrowRightDownLine,
+            CompassDirection::South => Icon::ArrowDownLine,
+            CompassDirection::SouthWest => Icon::ArrowLeftDownLine,
+            CompassDirection::West => Icon::ArrowLeftLine,
+            CompassDirection::NorthWest => Icon::ArrowLeftUpLine,
+        }
+    }
+}
+
+impl From<CompassDirection> for Icon {
+    fn from(direction: CompassDirection) -> Self {
+        direction.icon()
+    }
+}
@@ -0,0 +1,222 @@
+//! App-wide icon defaults.
+
+use crate::Size;
+use gpui::{
+    AbsoluteLength, AnyElement, App, Bounds, Element, ElementId, Global, GlobalElementId,
+    InspectorElementId, IntoElement, LayoutId, Pixels, Window, rems,
+};
+use std::cell::RefCell;
+use std::panic::Location;
+
+/// Edge lengths of the [`Size`] presets.
+///
+/// Install with `cx.set_global(IconSizes { .. })` to match a design system's scale; icons
+/// resolve their preset against it at render time.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IconSizes {
+    /// [`Size::XSmall`], 12px by default.
+    pub xsmall: AbsoluteLength,
+    /// [`Size::Small`], 14px by default.
+    pub small: AbsoluteLength,
+    /// [`Size::Medium`], 16px by default.
+    pub medium: AbsoluteLength,
+    /// [`Size::Large`], 24px by default.
+    pub large: AbsoluteLength,
+    /// [`Size::XLarge`], 32px by default.
+    pub xlarge: AbsoluteLength,
+    /// [`Size::XXLarge`], 40px by default.
+    pub xxlarge: AbsoluteLength,
+}
+
+impl Global for IconSizes {}
+
+impl Default for IconSizes {
+    fn default() -> Self {
+        Self {
+            xsmall: rems(0.75).into(),
+            small: rems(0.875).into(),
+            medium: rems(1.).into(),
+            large: rems(1.5).into(),
+            xlarge: rems(2.).into(),
+            xxlarge: rems(2.5).into(),
+        }
+    }
+}
+
+impl IconSizes {
+    /// Returns the app's sizes, or the defaults if none are installed.
+    pub fn global(cx: &App) -> Self {
+        cx.try_global::<Self>().copied().unwrap_or_default()
+    }
+
+    /// Returns the edge length of `size`.
+    pub fn length(&self, size: Size) -> AbsoluteLength {
+        match size {
+            Size::XSmall => self.xsmall,
+            Size::Small => self.small,
+            Size::Medium => self.medium,
+            Size::Large => self.large,
+            Size::XLarge => self.xlarge,
+            Size::XXLarge => self.xxlarge,
+            Size::Size(px) => px.into(),
+        }
+    }
+}
+
+/// Whether icons with both an outlined and a filled variant prefer the outline or the fill.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum IconStyle {
+    /// Outlined (`*Line`) variants.
+    #[default]
+    Line,
+    /// Filled (`*Fill`) variants.
+    Fill,
+}
+
+impl IconStyle {
+    /// Returns the style preferred where icons are currently rendering: the innermost
+    /// [`IconStyleScope`], else [`IconDefaults::style`].
+    pub fn current(cx: &App) -> Self {
+        cx.try_global::<StyleScopes>()
+            .and_then(|scopes| scopes.0.borrow().last().copied())
+            .unwrap_or_else(|| IconDefaults::global(cx).style)
+    }
+}
+
+/// Defaults for icons that don't set their own size or style.
+///
+/// Install with `cx.set_global(IconDefaults { .. })`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct IconDefaults {
+    /// Size of icons without an explicit size. `None` follows the text size.
+    pub size: Option<Size>,
+    /// Style of icons referenced by a style-agnostic base name.
+    pub style: IconStyle,
+}
+
+impl Global for IconDefaults {}
+
+impl IconDefaults {
+    /// Returns the app's defaults, or [`IconDefaults::default`] if none are installed.
+    pub fn global(cx: &App) -> Self {
+        cx.try_global::<Self>().copied().unwrap_or_default()
+    }
+}
+
+/// Styles of the [`IconStyleScope`]s currently being laid out or painted, innermost last.
+///
+/// Mutated through a shared reference so entering a scope doesn't notify global observers.
+#[derive(Default)]
+struct StyleScopes(RefCell<Vec<IconStyle>>);
+
+impl Global for StyleScopes {}
+
+/// Renders `child` with icons created by [`Icon::named`](crate::Icon::named) preferring `style`,
+/// e.g. to offer a "filled icons" preference for part of the UI.
+///
+/// The `id` keys the style the scope last drew with, so changing it redraws cached views below
+/// the scope; it must be unique among its siblings.
+pub fn icon_style_scope(
+    id: impl Into<ElementId>,
+    style: IconStyle,
+    child: impl IntoElement,
+) -> IconStyleScope {
+    IconStyleScope {
+        id: id.into(),
+        style,
+        child: child.into_any_element(),
+    }
+}
+
+/// An element overriding the preferred [`IconStyle`] of its subtree; see [`icon_style_scope`].
+pub struct IconStyleScope {
+    id: ElementId,
+    style: IconStyle,
+    child: AnyElement,
+}
+
+impl IconStyleScope {
+    fn scoped<R>(
+        &mut self,
+        window: &mut Window,
+        cx: &mut App,
+        f: impl FnOnce(&mut AnyElement, &mut Window, &mut App) -> R,
+    ) -> R {
+        if !cx.has_global::<StyleScopes>() {
+            cx.set_global(StyleScopes::default());
+        }
+        cx.global::<StyleScopes>().0.borrow_mut().push(self.style);
+        let result = f(&mut self.child, window, cx);
+        cx.global::<StyleScopes>().0.borrow_mut().pop();
+        result
+    }
+}
+
+impl IntoElement for IconStyleScope {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for IconStyleScope {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn source_location(&self) -> Option<&'static Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let layout_id = self.scoped(window, cx, |child, window, cx| {
+            child.request_layout(window, cx)
+        });
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        // Cached views below the scope don't re-render on their own when its style changes
+        let style = self.style;
+        window.with_element_state(id.unwrap(), |previous: Option<IconStyle>, window| {
+            if previous.is_some_and(|previous| previous != style) {
+                window.on_next_frame(|window, _| window.refresh());
+            }
+            ((), style)
+        });
+        self.scoped(window, cx, |child, window, cx| {
+            child.prepaint(window, cx);
+        });
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.scoped(window, cx, |child, window, cx| child.paint(window, cx));
+    }
+}
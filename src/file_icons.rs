@@ -0,0 +1,356 @@
+//! Icons for files and directories.
+
+use crate::sorted::is_strictly_sorted;
+use crate::{RemixIconName, document::Icon};
+use gpui::{App, Global};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Icons of well-known file names, lowercase and sorted for [`lookup`].
+const FILE_NAMES: &[(&str, Icon)] = &[
+    (".editorconfig", Icon::FileSettingsLine),
+    (".env", Icon::FileSettingsLine),
+    (".gitattributes", Icon::FileSettingsLine),
+    (".gitignore", Icon::FileSettingsLine),
+    (".npmrc", Icon::FileSettingsLine),
+    ("cargo.lock", Icon::FileLockLine),
+    ("cmakelists.txt", Icon::FileCodeLine),
+    ("copying", Icon::FilePaper2Line),
+    ("dockerfile", Icon::FileCodeLine),
+    ("flake.lock", Icon::FileLockLine),
+    ("gemfile", Icon::FileCodeLine),
+    ("gemfile.lock", Icon::FileLockLine),
+    ("justfile", Icon::FileCodeLine),
+    ("licence", Icon::FilePaper2Line),
+    ("license", Icon::FilePaper2Line),
+    ("license.md", Icon::FilePaper2Line),
+    ("license.txt", Icon::FilePaper2Line),
+    ("makefile", Icon::FileCodeLine),
+    ("package-lock.json", Icon::FileLockLine),
+    ("pnpm-lock.yaml", Icon::FileLockLine),
+    ("poetry.lock", Icon::FileLockLine),
+    ("procfile", Icon::FileCodeLine),
+    ("readme", Icon::FileInfoLine),
+    ("readme.md", Icon::FileInfoLine),
+    ("readme.txt", Icon::FileInfoLine),
+    ("yarn.lock", Icon::FileLockLine),
+];
+
+/// Icons of extensions without a registered MIME type, lowercase and sorted for [`lookup`].
+const EXTENSIONS: &[(&str, Icon)] = &[
+    ("bash", Icon::FileCodeLine),
+    ("bat", Icon::FileCodeLine),
+    ("c", Icon::FileCodeLine),
+    ("cc", Icon::FileCodeLine),
+    ("cfg", Icon::FileSettingsLine),
+    ("clj", Icon::FileCodeLine),
+    ("conf", Icon::FileSettingsLine),
+    ("cpp", Icon::FileCodeLine),
+    ("cs", Icon::FileCodeLine),
+    ("cxx", Icon::FileCodeLine),
+    ("dart", Icon::FileCodeLine),
+    ("env", Icon::FileSettingsLine),
+    ("erl", Icon::FileCodeLine),
+    ("ex", Icon::FileCodeLine),
+    ("exs", Icon::FileCodeLine),
+    ("fish", Icon::FileCodeLine),
+    ("glsl", Icon::FileCodeLine),
+    ("go", Icon::FileCodeLine),
+    ("graphql", Icon::FileCodeLine),
+    ("h", Icon::FileCodeLine),
+    ("hlsl", Icon::FileCodeLine),
+    ("hpp", Icon::FileCodeLine),
+    ("hs", Icon::FileCodeLine),
+    ("ini", Icon::FileSettingsLine),
+    ("java", Icon::FileCodeLine),
+    ("jsx", Icon::FileCodeLine),
+    ("kt", Icon::FileCodeLine),
+    ("kts", Icon::FileCodeLine),
+    ("lock", Icon::FileLockLine),
+    ("lua", Icon::FileCodeLine),
+    ("m", Icon::FileCodeLine),
+    ("ml", Icon::FileCodeLine),
+    ("mm", Icon::FileCodeLine),
+    ("php", Icon::FileCodeLine),
+    ("pl", Icon::FileCodeLine),
+    ("proto", Icon::FileCodeLine),
+    ("ps1", Icon::FileCodeLine),
+    ("py", Icon::FileCodeLine),
+    ("r", Icon::FileCodeLine),
+    ("rb", Icon::FileCodeLine),
+    ("rs", Icon::FileCodeLine),
+    ("scala", Icon::FileCodeLine),
+    ("sh", Icon::FileCodeLine),
+    ("sql", Icon::FileCodeLine),
+    ("svelte", Icon::FileCodeLine),
+    ("swift", Icon::FileCodeLine),
+    ("ts", Icon::FileCodeLine),
+    ("tsx", Icon::FileCodeLine),
+    ("vue", Icon::FileCodeLine),
+    ("wgsl", Icon::FileCodeLine),
+    ("zig", Icon::FileCodeLine),
+    ("zsh", Icon::FileCodeLine),
+];
+
+/// MIME types of extensions, lowercase and sorted for [`lookup`].
+const MIME_TYPES: &[(&str, &str)] = &[
+    ("7z", "application/x-7z-compressed"),
+    ("aac", "audio/aac"),
+    ("avi", "video/x-msvideo"),
+    ("avif", "image/avif"),
+    ("bmp", "image/bmp"),
+    ("bz2", "application/x-bzip2"),
+    ("css", "text/css"),
+    ("csv", "text/csv"),
+    ("doc", "application/msword"),
+    (
+        "docx",
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    ),
+    ("flac", "audio/flac"),
+    ("gif", "image/gif"),
+    ("gz", "application/gzip"),
+    ("heic", "image/heic"),
+    ("htm", "text/html"),
+    ("html", "text/html"),
+    ("ico", "image/vnd.microsoft.icon"),
+    ("jpeg", "image/jpeg"),
+    ("jpg", "image/jpeg"),
+    ("js", "text/javascript"),
+    ("json", "application/json"),
+    ("log", "text/plain"),
+    ("m4a", "audio/mp4"),
+    ("markdown", "text/markdown"),
+    ("md", "text/markdown"),
+    ("mjs", "text/javascript"),
+    ("mkv", "video/x-matroska"),
+    ("mov", "video/quicktime"),
+    ("mp3", "audio/mpeg"),
+    ("mp4", "video/mp4"),
+    ("odp", "application/vnd.oasis.opendocument.presentation"),
+    ("ods", "application/vnd.oasis.opendocument.spreadsheet"),
+    ("odt", "application/vnd.oasis.opendocument.text"),
+    ("ogg", "audio/ogg"),
+    ("opus", "audio/opus"),
+    ("pdf", "application/pdf"),
+    ("png", "image/png"),
+    ("ppt", "application/vnd.ms-powerpoint"),
+    (
+        "pptx",
+        "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+    ),
+    ("rar", "application/vnd.rar"),
+    ("rtf", "application/rtf"),
+    ("svg", "image/svg+xml"),
+    ("tar", "application/x-tar"),
+    ("tgz", "application/gzip"),
+    ("tif", "image/tiff"),
+    ("tiff", "image/tiff"),
+    ("toml", "application/toml"),
+    ("txt", "text/plain"),
+    ("wasm", "application/wasm"),
+    ("wav", "audio/wav"),
+    ("webm", "video/webm"),
+    ("webp", "image/webp"),
+    ("xls", "application/vnd.ms-excel"),
+    (
+        "xlsx",
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    ),
+    ("xml", "application/xml"),
+    ("xz", "application/x-xz"),
+    ("yaml", "application/yaml"),
+    ("yml", "application/yaml"),
+    ("zip", "application/zip"),
+    ("zst", "application/zstd"),
+];
+
+const _: () = assert!(
+    is_strictly_sorted(FILE_NAMES),
+    "FILE_NAMES must be sorted and free of duplicates"
+);
+const _: () = assert!(
+    is_strictly_sorted(EXTENSIONS),
+    "EXTENSIONS must be sorted and free of duplicates"
+);
+const _: () = assert!(
+    is_strictly_sorted(MIME_TYPES),
+    "MIME_TYPES must be sorted and free of duplicates"
+);
+
+/// Looks up `key` in a table sorted by key.
+fn lookup<T: Copy>(table: &[(&str, T)], key: &str) -> Option<T> {
+    table
+        .binary_search_by_key(&key, |(entry, _)| entry)
+        .ok()
+        .map(|index| table[index].1)
+}
+
+fn lowercase_file_name(path: &Path) -> Option<String> {
+    Some(path.file_name()?.to_str()?.to_lowercase())
+}
+
+fn lowercase_extension(path: &Path) -> Option<String> {
+    Some(path.extension()?.to_str()?.to_lowercase())
+}
+
+/// Returns the MIME type of the file at `path`, guessed from its extension.
+///
+/// ```
+/// use gpui_remixicon::mime_type;
+/// use std::path::Path;
+///
+/// assert_eq!(mime_type(Path::new("photo.JPG")), Some("image/jpeg"));
+/// ```
+pub fn mime_type(path: &Path) -> Option<&'static str> {
+    lookup(MIME_TYPES, &lowercase_extension(path)?)
+}
+
+/// Returns the icon of a file with the MIME type `mime`, e.g. from an HTTP response, or
+/// `FileLine` for unknown types. Parameters such as `; charset=utf-8` are ignored.
+pub fn mime_icon(mime: &str) -> RemixIconName {
+    let mime = mime.split(';').next().unwrap_or_default().trim();
+    let mime = mime.to_ascii_lowercase();
+    let (kind, subtype) = mime.split_once('/').unwrap_or((&mime, ""));
+
+    let icon = match (kind, subtype) {
+        ("application", "pdf") => Icon::FilePdf2Line,
+        ("application", "msword" | "rtf" | "vnd.oasis.opendocument.text") => Icon::FileWord2Line,
+        ("application", subtype) if subtype.contains("wordprocessingml") => Icon::FileWord2Line,
+        ("application", "vnd.ms-excel" | "vnd.oasis.opendocument.spreadsheet")
+        | ("text", "csv") => Icon::FileExcel2Line,
+        ("application", subtype) if subtype.contains("spreadsheetml") => Icon::FileExcel2Line,
+        ("application", "vnd.ms-powerpoint" | "vnd.oasis.opendocument.presentation") => {
+            Icon::FilePpt2Line
+        }
+        ("application", subtype) if subtype.contains("presentationml") => Icon::FilePpt2Line,
+        (
+            "application",
+            "zip" | "gzip" | "zstd" | "x-tar" | "x-bzip2" | "x-xz" | "x-7z-compressed" | "vnd.rar",
+        ) => Icon::FileZipLine,
+        ("application", "toml" | "yaml") => Icon::FileSettingsLine,
+        ("application", "json" | "xml" | "javascript" | "wasm") => Icon::FileCodeLine,
+        ("text", "html" | "css" | "javascript" | "xml") => Icon::FileCodeLine,
+        ("text", "markdown") => Icon::MarkdownLine,
+        ("image", "gif") => Icon::FileGifLine,
+        ("image", _) => Icon::FileImageLine,
+        ("audio", _) => Icon::FileMusicLine,
+        ("video", _) => Icon::FileVideoLine,
+        ("text", _) => Icon::FileTextLine,
+        _ => Icon::FileLine,
+    };
+    icon.into()
+}
+
+/// Returns the icon of the file or directory at `path` from the built-in tables: its file name
+/// (e.g. `Cargo.lock`), else its extension (e.g. `.rs`), else the icon of its [`mime_type`].
+/// Directories are `FolderLine`, or `FolderOpenLine` when `is_open`.
+///
+/// Only the path is inspected, not the file system. Use [`FileIcons`] to override the tables.
+///
+/// ```
+/// use gpui_remixicon::{RemixIconName, file_icon};
+/// use std::path::Path;
+///
+/// assert_eq!(
+///     file_icon(Path::new("src/main.rs"), false, false),
+///     RemixIconName::DocumentFileCodeLine
+/// );
+/// ```
+pub fn file_icon(path: &Path, is_dir: bool, is_open: bool) -> RemixIconName {
+    if is_dir {
+        let icon = if is_open {
+            Icon::FolderOpenLine
+        } else {
+            Icon::FolderLine
+        };
+        return icon.into();
+    }
+
+    let by_name = lowercase_file_name(path).and_then(|name| lookup(FILE_NAMES, &name));
+    let by_extension = || lowercase_extension(path).and_then(|ext| lookup(EXTENSIONS, &ext));
+    match by_name.or_else(by_extension) {
+        Some(icon) => icon.into(),
+        None => mime_icon(mime_type(path).unwrap_or_default()),
+    }
+}
+
+/// App-specific file icons, taking precedence over the built-in tables of [`file_icon`].
+///
+/// Register overrides on the global, e.g. when a plugin adds a file type:
+///
+/// ```
+/// use gpui::App;
+/// use gpui_remixicon::{FileIcons, RemixIconName};
+///
+/// fn register_proto_icons(cx: &mut App) {
+///     let icons = cx.default_global::<FileIcons>();
+///     icons.register_extension("proto", RemixIconName::DevelopmentCodeSSlashLine);
+///     icons.register_file_name("BUILD.bazel", RemixIconName::DevelopmentCodeBoxLine);
+/// }
+/// ```
+///
+/// and resolve icons with [`FileIcons::resolve`].
+#[derive(Debug, Clone, Default)]
+pub struct FileIcons {
+    file_names: HashMap<String, RemixIconName>,
+    extensions: HashMap<String, RemixIconName>,
+    mime_types: HashMap<String, RemixIconName>,
+}
+
+impl Global for FileIcons {}
+
+impl FileIcons {
+    /// Use `icon` for files named `name`, compared case-insensitively.
+    pub fn register_file_name(&mut self, name: &str, icon: impl Into<RemixIconName>) {
+        self.file_names.insert(name.to_lowercase(), icon.into());
+    }
+
+    /// Use `icon` for files with the extension `extension` (without the dot), compared
+    /// case-insensitively.
+    pub fn register_extension(&mut self, extension: &str, icon: impl Into<RemixIconName>) {
+        let extension = extension.trim_start_matches('.');
+        self.extensions
+            .insert(extension.to_lowercase(), icon.into());
+    }
+
+    /// Use `icon` for files of the MIME type `mime`, e.g. `image/png`.
+    pub fn register_mime_type(&mut self, mime: &str, icon: impl Into<RemixIconName>) {
+        self.mime_types
+            .insert(mime.to_ascii_lowercase(), icon.into());
+    }
+
+    /// Returns the icon of the file or directory at `path`: the registered icon of its file name,
+    /// extension or MIME type, in that order, else the built-in [`file_icon`].
+    pub fn icon(&self, path: &Path, is_dir: bool, is_open: bool) -> RemixIconName {
+        if !is_dir {
+            let by_name = || lowercase_file_name(path).and_then(|name| self.file_names.get(&name));
+            let by_extension =
+                || lowercase_extension(path).and_then(|ext| self.extensions.get(&ext));
+            let by_mime = || mime_type(path).and_then(|mime| self.mime_types.get(mime));
+            if let Some(icon) = by_name().or_else(by_extension).or_else(by_mime) {
+                return *icon;
+            }
+        }
+        file_icon(path, is_dir, is_open)
+    }
+
+    /// Returns the icon of a file with the MIME type `mime`: the registered icon, else the
+    /// built-in [`mime_icon`].
+    pub fn mime_icon(&self, mime: &str) -> RemixIconName {
+        let essence = mime.split(';').next().unwrap_or_default().trim();
+        match self.mime_types.get(&essence.to_ascii_lowercase()) {
+            Some(icon) => *icon,
+            None => mime_icon(mime),
+        }
+    }
+
+    /// Resolves the icon of the file or directory at `path` against the app's registered
+    /// overrides, if any.
+    pub fn resolve(path: &Path, is_dir: bool, is_open: bool, cx: &App) -> RemixIconName {
+        match cx.try_global::<Self>() {
+            Some(icons) => icons.icon(path, is_dir, is_open),
+            None => file_icon(path, is_dir, is_open),
+        }
+    }
+}
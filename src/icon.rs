@@ -1,10 +1,17 @@
-use crate::RemixIcon;
+use crate::{
+    IconDefaults, IconRole, IconSizes, IconStyle, IconTheme, RemixIcon, RemixIconBase,
+    RemixIconName,
+};
 use gpui::{
-    AnyElement, App, AppContext as _, Context, Entity, Hsla, IntoElement, Pixels, Radians, Render,
-    RenderOnce, SharedString, StyleRefinement, Styled, Svg, Transformation, Window,
-    prelude::FluentBuilder as _, svg,
+    AbsoluteLength, AnyElement, App, AppContext as _, Context, Element, ElementId, Entity, Hitbox,
+    Hsla, InteractiveElement, IntoElement, Pixels, Radians, Render, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, StyleRefinement, Styled, Svg, Transformation, Window,
+    prelude::FluentBuilder as _, size, svg,
 };
 
+/// Opacity of [disabled](Icon::disabled) icons.
+pub(crate) const DISABLED_OPACITY: f32 = 0.5;
+
 /// Types implementing this trait can automatically be converted to [`Icon`].
 ///
 /// This allows RemixIcon types to function as drop-in replacements for other UI components.
@@ -26,6 +33,8 @@ impl<T: IconNamed> From<T> for Icon {
 }
 
 /// Icon size variants.
+///
+/// Presets resolve against the [`IconSizes`] global; the pixel sizes below are its defaults.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Size {
     /// Extra small: 12px
@@ -36,6 +45,10 @@ pub enum Size {
     Medium,
     /// Large: 24px
     Large,
+    /// Extra large: 32px
+    XLarge,
+    /// Extra extra large: 40px
+    XXLarge,
     /// Custom size in pixels
     Size(Pixels),
 }
@@ -55,40 +68,55 @@ pub trait Sizable {
 /// A RemixIcon component for GPUI.
 ///
 /// This type is designed to be compatible with gpui-component's Icon type.
-#[derive(IntoElement)]
+///
+/// All presentation state lives in plain fields, so an icon rendered inline and the same icon
+/// rendered as an `Entity<Icon>` view (see [`Icon::view`]) draw identically.
+#[derive(Clone, IntoElement)]
 pub struct Icon {
-    base: Svg,
-    style: StyleRefinement,
-    path: SharedString,
-    text_color: Option<Hsla>,
-    size: Option<Size>,
-    rotation: Option<Radians>,
+    pub(crate) style: StyleRefinement,
+    pub(crate) path: SharedString,
+    /// Outlined and filled paths of an icon created with [`Icon::named`].
+    pub(crate) styled_paths: Option<[SharedString; 2]>,
+    pub(crate) text_color: Option<Hsla>,
+    pub(crate) role: Option<IconRole>,
+    pub(crate) size: Option<Size>,
+    pub(crate) transform: Option<Transformation>,
+    pub(crate) rotation: Option<Radians>,
+    pub(crate) flip_horizontal: bool,
+    pub(crate) flip_vertical: bool,
+    pub(crate) aria_label: Option<SharedString>,
+    pub(crate) decorative: bool,
+    pub(crate) id: Option<ElementId>,
+    pub(crate) hover_color: Option<Hsla>,
+    pub(crate) active_color: Option<Hsla>,
+    pub(crate) group_hover_color: Option<(SharedString, Hsla)>,
+    pub(crate) disabled: bool,
 }
 
 impl Default for Icon {
     fn default() -> Self {
         Self {
-            base: svg().flex_none().size_4(),
             style: StyleRefinement::default(),
             path: "".into(),
+            styled_paths: None,
             text_color: None,
+            role: None,
             size: None,
+            transform: None,
             rotation: None,
+            flip_horizontal: false,
+            flip_vertical: false,
+            aria_label: None,
+            decorative: false,
+            id: None,
+            hover_color: None,
+            active_color: None,
+            group_hover_color: None,
+            disabled: false,
         }
     }
 }
 
-impl Clone for Icon {
-    fn clone(&self) -> Self {
-        let mut this = Self::default().path(self.path.clone());
-        this.style = self.style.clone();
-        this.rotation = self.rotation;
-        this.size = self.size;
-        this.text_color = self.text_color;
-        this
-    }
-}
-
 impl Icon {
     /// Create a new Icon from any type that implements IconNamed.
     pub fn new(icon: impl Into<Icon>) -> Self {
@@ -99,22 +127,64 @@ impl Icon {
         Self::default().path(name.path())
     }
 
+    /// Create an icon from a style-agnostic name, drawn outlined or filled depending on the
+    /// [`IconStyle`] preferred where it renders.
+    ///
+    /// ```
+    /// use gpui_remixicon::{Icon, system};
+    ///
+    /// let star = Icon::named(system::IconBase::Star);
+    /// ```
+    pub fn named(name: impl RemixIconBase + Copy) -> Self {
+        let paths = [name.path(IconStyle::Line), name.path(IconStyle::Fill)];
+        let mut this = Self::default().path(paths[0].clone());
+        this.styled_paths = Some(paths);
+        this
+    }
+
     /// Set the icon path of the Assets bundle.
     ///
     /// For example: `icons/foo.svg`
     pub fn path(mut self, path: impl Into<SharedString>) -> Self {
         self.path = path.into();
+        self.styled_paths = None;
         self
     }
 
+    /// The path to draw, in the preferred style for icons created with [`Icon::named`].
+    pub(crate) fn resolved_path(&self, cx: &App) -> SharedString {
+        match &self.styled_paths {
+            Some(paths) => paths[IconStyle::current(cx) as usize].clone(),
+            None => self.path.clone(),
+        }
+    }
+
+    /// Draw an icon created with [`Icon::named`] in `style`, regardless of the preferred style.
+    pub(crate) fn styled(self, style: IconStyle) -> Self {
+        match &self.styled_paths {
+            Some(paths) => {
+                let path = paths[style as usize].clone();
+                self.path(path)
+            }
+            None => self,
+        }
+    }
+
     /// Create a new view for the icon.
+    ///
+    /// The view always draws this icon; use [`IconView`](crate::IconView) for one that can be
+    /// updated.
     pub fn view(self, cx: &mut App) -> Entity<Icon> {
         cx.new(|_| self)
     }
 
     /// Apply a transformation to the icon.
+    ///
+    /// GPUI doesn't expose the components of a [`Transformation`], so they can't be composed:
+    /// [`rotate`](Self::rotate) replaces its rotation, and a flip replaces its scale with `-1` on
+    /// the flipped axes and `1` on the others. Its translation is always kept.
     pub fn transform(mut self, transformation: Transformation) -> Self {
-        self.base = self.base.with_transformation(transformation);
+        self.transform = Some(transformation);
         self
     }
 
@@ -123,12 +193,212 @@ impl Icon {
         Self::default()
     }
 
-    /// Rotate the icon by the given angle.
+    /// Color the icon by a semantic role, resolved against the [`IconTheme`] at render time.
+    ///
+    /// An explicit `text_color` takes precedence over the role.
+    pub fn role(mut self, role: IconRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// The explicit or role color of the icon, if any.
+    fn color(&self, cx: &App) -> Option<Hsla> {
+        self.text_color
+            .or_else(|| self.role.map(|role| IconTheme::resolve(role, cx)))
+    }
+
+    /// The edge length of the icon when its style sets no size: its own size, else the default
+    /// size, else the text size.
+    fn length(&self, window: &Window, cx: &App) -> AbsoluteLength {
+        match self.size.or(IconDefaults::global(cx).size) {
+            Some(size) => IconSizes::global(cx).length(size),
+            None => window
+                .text_style()
+                .font_size
+                .to_pixels(window.rem_size())
+                .into(),
+        }
+    }
+
+    /// Set the name assistive technology announces for the icon, replacing the default derived
+    /// from its RemixIcon label.
+    pub fn aria_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.aria_label = Some(label.into());
+        self.decorative = false;
+        self
+    }
+
+    /// Mark the icon as purely decorative, so it has no accessible name.
+    pub fn decorative(mut self) -> Self {
+        self.decorative = true;
+        self
+    }
+
+    /// Whether the icon was marked [`decorative`](Self::decorative).
+    pub fn is_decorative(&self) -> bool {
+        self.decorative
+    }
+
+    /// The name assistive technology should announce for the icon.
+    ///
+    /// This is the [`aria_label`](Self::aria_label) if set, else the generated label of the
+    /// RemixIcon at the icon's path (e.g. `arrow up s`), and `None` for decorative icons and
+    /// custom paths. GPUI does not expose an accessibility tree yet, so elements built on `Icon`
+    /// (such as tooltips) read it from here.
+    pub fn accessible_label(&self) -> Option<SharedString> {
+        if self.decorative {
+            return None;
+        }
+        if let Some(label) = &self.aria_label {
+            return Some(label.clone());
+        }
+        let label = self.path.rsplit('/').next()?.strip_suffix(".svg")?;
+        let name = RemixIconName::from_label(label)?;
+        Some(SharedString::new_static(name.accessible_label()))
+    }
+
+    /// Set the element id of the icon, which GPUI needs to track presses for
+    /// [`active_color`](Self::active_color).
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Set the color of the icon while the mouse is over it.
+    pub fn hover_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.hover_color = Some(color.into());
+        self
+    }
+
+    /// Set the color of the icon while it is pressed. Only applies to icons with an
+    /// [`id`](Self::id).
+    pub fn active_color(mut self, color: impl Into<Hsla>) -> Self {
+        self.active_color = Some(color.into());
+        self
+    }
+
+    /// Set the color of the icon while the mouse is over any element of `group`, e.g. a list row
+    /// created with `div().group("row")`.
+    pub fn group_hover_color(
+        mut self,
+        group: impl Into<SharedString>,
+        color: impl Into<Hsla>,
+    ) -> Self {
+        self.group_hover_color = Some((group.into(), color.into()));
+        self
+    }
+
+    /// Disable the icon: it is drawn at reduced opacity and ignores its hover and pressed colors.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Whether the icon is [disabled](Self::disabled).
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    /// Build the element drawing the icon, shared by the `RenderOnce` and `Render` impls.
+    fn element(&self, window: &Window, cx: &App) -> AnyElement {
+        let text_color = self.color(cx).unwrap_or_else(|| window.text_style().color);
+        let has_base_size = self.style.size.width.is_some() || self.style.size.height.is_some();
+
+        let mut base = svg().flex_none();
+        *base.style() = self.style.clone();
+
+        let base = base
+            .flex_shrink_0()
+            .text_color(text_color)
+            .when(!has_base_size || self.size.is_some(), |this| {
+                this.size(self.length(window, cx))
+            })
+            .when_some(self.transformation(), |this, transformation| {
+                this.with_transformation(transformation)
+            })
+            .path(self.resolved_path(cx));
+        self.with_states(base, cx)
+    }
+
+    /// Apply the disabled, hover and pressed states to the rendered `svg`.
+    fn with_states(&self, svg: Svg, cx: &App) -> AnyElement {
+        if self.disabled {
+            return self.finish(svg.opacity(DISABLED_OPACITY), cx);
+        }
+
+        let svg = svg
+            .when_some(self.group_hover_color.clone(), |this, (group, color)| {
+                this.group_hover(group, |style| style.text_color(color))
+            })
+            .when_some(self.hover_color, |this, color| {
+                this.hover(|style| style.text_color(color))
+            });
+        match &self.id {
+            Some(id) => {
+                let svg = svg
+                    .id(id.clone())
+                    .when_some(self.active_color, |this, color| {
+                        this.active(|style| style.text_color(color))
+                    });
+                self.finish(svg, cx)
+            }
+            None => self.finish(svg, cx),
+        }
+    }
+
+    /// Erase the type of the rendered svg, recording what GPUI draws for
+    /// [`test_support`](crate::test_support) when it is enabled.
+    #[cfg_attr(not(feature = "test-support"), allow(unused_variables))]
+    fn finish<E>(&self, svg: E, cx: &App) -> AnyElement
+    where
+        E: Element<PrepaintState = Option<Hitbox>> + InteractiveElement,
+    {
+        #[cfg(feature = "test-support")]
+        return crate::test_support::probe(self, svg, cx);
+        #[cfg(not(feature = "test-support"))]
+        svg.into_any_element()
+    }
+
+    /// Rotate the icon by the given angle, replacing the rotation of its
+    /// [`transform`](Self::transform).
     pub fn rotate(mut self, radians: impl Into<Radians>) -> Self {
         self.rotation = Some(radians.into());
         self
     }
 
+    /// Mirror the icon horizontally, e.g. for right-to-left layouts. Replaces the scale of its
+    /// [`transform`](Self::transform).
+    pub fn flip_horizontal(mut self) -> Self {
+        self.flip_horizontal = true;
+        self
+    }
+
+    /// Mirror the icon vertically. Replaces the scale of its [`transform`](Self::transform).
+    pub fn flip_vertical(mut self) -> Self {
+        self.flip_vertical = true;
+        self
+    }
+
+    /// The transformation the icon is drawn with around its center: its
+    /// [`transform`](Self::transform), updated by its rotation and flips.
+    pub fn transformation(&self) -> Option<Transformation> {
+        let flipped = self.flip_horizontal || self.flip_vertical;
+        if self.transform.is_none() && self.rotation.is_none() && !flipped {
+            return None;
+        }
+
+        let mut transformation = self.transform.unwrap_or_default();
+        if let Some(rotation) = self.rotation {
+            transformation = transformation.with_rotation(rotation);
+        }
+        if flipped {
+            let scale = |flip| if flip { -1. } else { 1. };
+            transformation = transformation
+                .with_scaling(size(scale(self.flip_horizontal), scale(self.flip_vertical)));
+        }
+        Some(transformation)
+    }
+
     /// Set extra small size (12px).
     pub fn xsmall(self) -> Self {
         self.with_size(Size::XSmall)
@@ -149,6 +419,16 @@ impl Icon {
         self.with_size(Size::Large)
     }
 
+    /// Set extra large size (32px).
+    pub fn xlarge(self) -> Self {
+        self.with_size(Size::XLarge)
+    }
+
+    /// Set extra extra large size (40px).
+    pub fn xxlarge(self) -> Self {
+        self.with_size(Size::XXLarge)
+    }
+
     /// Set a custom size in pixels.
     pub fn custom_size(self, px: impl Into<Pixels>) -> Self {
         self.with_size(Size::Size(px.into()))
@@ -174,28 +454,8 @@ impl Sizable for Icon {
 }
 
 impl RenderOnce for Icon {
-    fn render(self, window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let text_color = self.text_color.unwrap_or_else(|| window.text_style().color);
-        let text_size = window.text_style().font_size.to_pixels(window.rem_size());
-        let has_base_size = self.style.size.width.is_some() || self.style.size.height.is_some();
-
-        let mut base = self.base;
-        *base.style() = self.style;
-
-        base.flex_shrink_0()
-            .text_color(text_color)
-            .when(!has_base_size, |this| this.size(text_size))
-            .when_some(self.size, |this, size| match size {
-                Size::Size(px) => this.size(px),
-                Size::XSmall => this.size_3(),
-                Size::Small => this.size_3p5(),
-                Size::Medium => this.size_4(),
-                Size::Large => this.size_6(),
-            })
-            .path(self.path)
-            .when_some(self.rotation, |this, rotation| {
-                this.with_transformation(Transformation::rotate(rotation))
-            })
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        self.element(window, cx)
     }
 }
 
@@ -206,27 +466,7 @@ impl From<Icon> for AnyElement {
 }
 
 impl Render for Icon {
-    fn render(&mut self, window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
-        let text_color = self.text_color.unwrap_or_else(|| window.text_style().color);
-        let text_size = window.text_style().font_size.to_pixels(window.rem_size());
-        let has_base_size = self.style.size.width.is_some() || self.style.size.height.is_some();
-
-        let mut base = svg().flex_none();
-        *base.style() = self.style.clone();
-
-        base.flex_shrink_0()
-            .text_color(text_color)
-            .when(!has_base_size, |this| this.size(text_size))
-            .when_some(self.size, |this, size| match size {
-                Size::Size(px) => this.size(px),
-                Size::XSmall => this.size_3(),
-                Size::Small => this.size_3p5(),
-                Size::Medium => this.size_4(),
-                Size::Large => this.size_6(),
-            })
-            .path(self.path.clone())
-            .when_some(self.rotation, |this, rotation| {
-                this.with_transformation(Transformation::rotate(rotation))
-            })
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        self.element(window, cx)
     }
 }
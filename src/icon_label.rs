@@ -0,0 +1,144 @@
+//! Icons paired with text.
+
+use crate::Icon;
+use gpui::{
+    AbsoluteLength, App, Div, IntoElement, ParentElement as _, Refineable as _, RenderOnce,
+    SharedString, StyleRefinement, Styled, Window, div, prelude::FluentBuilder as _, rems,
+};
+
+/// Spacing between the icon and the text of an [`IconLabel`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum IconGap {
+    /// 2px
+    XSmall,
+    /// 4px
+    Small,
+    /// 6px (default)
+    #[default]
+    Medium,
+    /// 8px
+    Large,
+    /// Custom spacing
+    Custom(AbsoluteLength),
+}
+
+impl IconGap {
+    /// Returns the spacing as a length.
+    pub fn length(self) -> AbsoluteLength {
+        match self {
+            IconGap::XSmall => rems(0.125).into(),
+            IconGap::Small => rems(0.25).into(),
+            IconGap::Medium => rems(0.375).into(),
+            IconGap::Large => rems(0.5).into(),
+            IconGap::Custom(length) => length,
+        }
+    }
+}
+
+/// Which side of the text an [`IconLabel`] draws its icon on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum IconPosition {
+    /// Before the text.
+    #[default]
+    Leading,
+    /// After the text.
+    Trailing,
+}
+
+/// An icon next to a label, e.g. for menu items, tabs and list rows.
+///
+/// The icon is centered on the first line of text: it sits in a box as tall as the label's line
+/// height, so it lines up the same way at every [`Size`](crate::Size) and wraps with
+/// multi-line labels instead of floating to their middle. Text styles set on the label (color,
+/// font size, ...) apply to the icon too unless it sets its own.
+///
+/// ```
+/// use gpui_remixicon::{IconGap, IconLabel, system};
+///
+/// let item = IconLabel::new(system::Icon::SettingsLine, "Settings")
+///     .gap(IconGap::Small)
+///     .truncate();
+/// ```
+#[derive(IntoElement)]
+pub struct IconLabel {
+    base: Div,
+    icon: Icon,
+    label: SharedString,
+    position: IconPosition,
+    gap: IconGap,
+    truncate: bool,
+}
+
+impl IconLabel {
+    /// Create a label drawing `icon` before `label`.
+    pub fn new(icon: impl Into<Icon>, label: impl Into<SharedString>) -> Self {
+        Self {
+            base: div(),
+            icon: icon.into(),
+            label: label.into(),
+            position: IconPosition::default(),
+            gap: IconGap::default(),
+            truncate: false,
+        }
+    }
+
+    /// Set which side of the text the icon is drawn on.
+    pub fn position(mut self, position: IconPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Draw the icon after the text.
+    pub fn trailing(self) -> Self {
+        self.position(IconPosition::Trailing)
+    }
+
+    /// Set the spacing between the icon and the text.
+    pub fn gap(mut self, gap: IconGap) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Keep the text on one line, cutting it off with an ellipsis when it doesn't fit. The icon
+    /// always stays visible.
+    pub fn truncate(mut self) -> Self {
+        self.truncate = true;
+        self
+    }
+}
+
+impl Styled for IconLabel {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for IconLabel {
+    fn render(mut self, window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        // Text styles set on the label only apply to the window once its children render
+        let mut text_style = window.text_style();
+        if let Some(text) = &self.base.style().text {
+            text_style.refine(text);
+        }
+        let line_height = text_style.line_height_in_pixels(window.rem_size());
+        let icon = div()
+            .flex()
+            .flex_none()
+            .items_center()
+            .h(line_height)
+            .child(self.icon);
+        let label = div()
+            .when(self.truncate, |this| this.min_w_0().truncate())
+            .child(self.label);
+
+        self.base
+            .flex()
+            .items_start()
+            .gap(self.gap.length())
+            .when(self.truncate, |this| this.min_w_0())
+            .map(|this| match self.position {
+                IconPosition::Leading => this.child(icon).child(label),
+                IconPosition::Trailing => this.child(label).child(icon),
+            })
+    }
+}
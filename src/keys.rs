@@ -0,0 +1,260 @@
+//! Keyboard shortcuts.
+
+use crate::{Icon, RemixIconName, Sizable, Size, arrows, development, editor, system};
+use gpui::{
+    App, Div, IntoElement, InvalidKeystrokeError, Keystroke, ParentElement as _, RenderOnce,
+    SharedString, StyleRefinement, Styled, Window, div, prelude::FluentBuilder as _, rems,
+};
+
+/// One part of a keystroke: a modifier or the key itself.
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeyGlyph {
+    /// A RemixIcon glyph, e.g. `CommandLine` for the Command key.
+    Icon {
+        /// The glyph.
+        icon: RemixIconName,
+        /// The name of the key, used as the accessible label of the glyph.
+        label: &'static str,
+    },
+    /// Text for keys without a RemixIcon glyph on this platform, e.g. `"Ctrl"` or `"K"`.
+    Text(SharedString),
+}
+
+impl KeyGlyph {
+    fn icon(icon: impl Into<RemixIconName>, label: &'static str) -> Self {
+        Self::Icon {
+            icon: icon.into(),
+            label,
+        }
+    }
+
+    fn text(text: impl Into<SharedString>) -> Self {
+        Self::Text(text.into())
+    }
+}
+
+/// The platform whose keyboard conventions [`key_icon_for`] and [`KeyBinding`] follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyPlatform {
+    /// macOS: symbols and icons, no separators.
+    MacOs,
+    /// Windows: spelled out modifiers, the platform key is `Win`.
+    Windows,
+    /// Linux and other platforms: spelled out modifiers, the platform key is `Super`.
+    Linux,
+}
+
+impl KeyPlatform {
+    /// Returns the platform the app is built for.
+    pub const fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::MacOs
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else {
+            Self::Linux
+        }
+    }
+}
+
+impl Default for KeyPlatform {
+    fn default() -> Self {
+        Self::current()
+    }
+}
+
+/// Returns the glyphs of `keystroke` on the current platform: its modifiers in the platform's
+/// order, then its key.
+///
+/// On macOS, Control, Command, Return, Delete and the arrow keys are drawn with icons and Option
+/// and Shift with their symbols. Elsewhere modifiers are spelled out (`Ctrl`, `Alt`, `Shift`,
+/// `Super` or `Win`), and only Enter, Backspace, Space and the arrow keys get icons. Other keys are
+/// shown as text, letters uppercased.
+///
+/// ```
+/// use gpui::Keystroke;
+/// use gpui_remixicon::{KeyGlyph, key_icon};
+///
+/// let glyphs = key_icon(&Keystroke::parse("ctrl-enter").unwrap());
+/// assert_eq!(glyphs.len(), 2);
+/// assert!(matches!(glyphs[1], KeyGlyph::Icon { label: "Enter" | "Return", .. }));
+/// ```
+pub fn key_icon(keystroke: &Keystroke) -> Vec<KeyGlyph> {
+    key_icon_for(keystroke, KeyPlatform::current())
+}
+
+/// Returns the glyphs of `keystroke` on `platform`, e.g. to document the shortcuts of every
+/// platform. See [`key_icon`].
+pub fn key_icon_for(keystroke: &Keystroke, platform: KeyPlatform) -> Vec<KeyGlyph> {
+    let mac = platform == KeyPlatform::MacOs;
+    let modifiers = &keystroke.modifiers;
+    let mut glyphs = Vec::new();
+    if modifiers.function {
+        glyphs.push(KeyGlyph::text(if mac { "fn" } else { "Fn" }));
+    }
+    if mac {
+        // Apple's order: ⌃⌥⇧⌘
+        if modifiers.control {
+            glyphs.push(KeyGlyph::icon(arrows::Icon::ArrowUpSLine, "Control"));
+        }
+        if modifiers.alt {
+            glyphs.push(KeyGlyph::text("⌥"));
+        }
+        if modifiers.shift {
+            glyphs.push(KeyGlyph::text("⇧"));
+        }
+        if modifiers.platform {
+            glyphs.push(KeyGlyph::icon(development::Icon::CommandLine, "Command"));
+        }
+    } else {
+        if modifiers.control {
+            glyphs.push(KeyGlyph::text("Ctrl"));
+        }
+        if modifiers.alt {
+            glyphs.push(KeyGlyph::text("Alt"));
+        }
+        if modifiers.shift {
+            glyphs.push(KeyGlyph::text("Shift"));
+        }
+        if modifiers.platform {
+            glyphs.push(KeyGlyph::text(if platform == KeyPlatform::Windows {
+                "Win"
+            } else {
+                "Super"
+            }));
+        }
+    }
+    glyphs.push(key_glyph(&keystroke.key, mac));
+    glyphs
+}
+
+fn key_glyph(key: &str, mac: bool) -> KeyGlyph {
+    match key {
+        "enter" => KeyGlyph::icon(
+            arrows::Icon::CornerDownLeftLine,
+            if mac { "Return" } else { "Enter" },
+        ),
+        "backspace" => KeyGlyph::icon(
+            system::Icon::DeleteBackLine,
+            if mac { "Delete" } else { "Backspace" },
+        ),
+        "space" => KeyGlyph::icon(editor::Icon::Space, "Space"),
+        "up" => KeyGlyph::icon(arrows::Icon::ArrowUpLine, "Up"),
+        "down" => KeyGlyph::icon(arrows::Icon::ArrowDownLine, "Down"),
+        "left" => KeyGlyph::icon(arrows::Icon::ArrowLeftLine, "Left"),
+        "right" => KeyGlyph::icon(arrows::Icon::ArrowRightLine, "Right"),
+        "tab" if mac => KeyGlyph::text("⇥"),
+        "escape" if mac => KeyGlyph::text("⎋"),
+        "escape" => KeyGlyph::text("Esc"),
+        "delete" => KeyGlyph::text("Del"),
+        "insert" => KeyGlyph::text("Ins"),
+        "pageup" => KeyGlyph::text("PgUp"),
+        "pagedown" => KeyGlyph::text("PgDn"),
+        key => {
+            let mut chars = key.chars();
+            match chars.next() {
+                Some(first) => {
+                    KeyGlyph::text(first.to_uppercase().chain(chars).collect::<String>())
+                }
+                None => KeyGlyph::text(""),
+            }
+        }
+    }
+}
+
+/// A keyboard shortcut, e.g. next to a command palette entry, drawing each keystroke with the
+/// glyphs of [`key_icon_for`].
+///
+/// On macOS the glyphs of a keystroke sit next to each other (`⌘⇧P`); elsewhere they are joined
+/// with `+` (`Ctrl+Shift+P`). Keystrokes of a sequence are spaced apart. Text styles set on the
+/// binding apply to both the text and the icons. Bindings follow the current platform unless
+/// given another with [`platform`](Self::platform).
+///
+/// ```
+/// use gpui_remixicon::KeyBinding;
+///
+/// let shortcut = KeyBinding::parse("cmd-k cmd-s").unwrap();
+/// ```
+#[derive(IntoElement)]
+pub struct KeyBinding {
+    base: Div,
+    keystrokes: Vec<Keystroke>,
+    platform: KeyPlatform,
+    size: Option<Size>,
+}
+
+impl KeyBinding {
+    /// Create a binding drawing a sequence of keystrokes.
+    pub fn new(keystrokes: impl IntoIterator<Item = Keystroke>) -> Self {
+        Self {
+            base: div(),
+            keystrokes: keystrokes.into_iter().collect(),
+            platform: KeyPlatform::current(),
+            size: None,
+        }
+    }
+
+    /// Create a binding from GPUI's keystroke syntax, keystrokes separated by spaces, e.g.
+    /// `"cmd-shift-p"` or `"ctrl-k ctrl-s"`.
+    pub fn parse(source: &str) -> Result<Self, InvalidKeystrokeError> {
+        source
+            .split_whitespace()
+            .map(Keystroke::parse)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+
+    /// Draw the binding the way `platform` shows shortcuts.
+    pub fn platform(mut self, platform: KeyPlatform) -> Self {
+        self.platform = platform;
+        self
+    }
+
+    /// The keystrokes of the binding.
+    pub fn keystrokes(&self) -> &[Keystroke] {
+        &self.keystrokes
+    }
+}
+
+impl Styled for KeyBinding {
+    fn style(&mut self) -> &mut StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl Sizable for KeyBinding {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+}
+
+impl RenderOnce for KeyBinding {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let (size, platform) = (self.size, self.platform);
+        self.base
+            .flex()
+            .items_center()
+            .gap(rems(0.375))
+            .children(self.keystrokes.iter().map(|keystroke| {
+                let mut children = Vec::new();
+                for glyph in key_icon_for(keystroke, platform) {
+                    if platform != KeyPlatform::MacOs && !children.is_empty() {
+                        children.push("+".into_any_element());
+                    }
+                    children.push(match glyph {
+                        KeyGlyph::Icon { icon, label } => Icon::new(icon)
+                            .aria_label(label)
+                            .when_some(size, |icon, size| icon.with_size(size))
+                            .into_any_element(),
+                        KeyGlyph::Text(text) => text.into_any_element(),
+                    });
+                }
+                div()
+                    .flex()
+                    .items_center()
+                    .gap(rems(0.125))
+                    .children(children)
+            }))
+    }
+}
@@ -0,0 +1,115 @@
+//! Serialization of icons as RemixIcon labels such as `arrow-up-s-line`.
+//!
+//! Icons serialize to their bare label. Fields that should also accept labels qualified with their
+//! category module name, e.g. `arrows/arrow-up-s-line`, opt in with [`qualified`]:
+//!
+//! ```
+//! use gpui_remixicon::arrows;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Toolbar {
+//!     #[serde(with = "gpui_remixicon::label::qualified")]
+//!     back: arrows::Icon,
+//! }
+//! ```
+
+use crate::CATEGORY_LABELS;
+
+/// Icon types that serialize as their RemixIcon label.
+///
+/// Implemented by every category's `Icon` enum and by [`RemixIconName`](crate::RemixIconName).
+pub trait Labeled: Copy {
+    /// The category module this type is restricted to, if any.
+    const CATEGORY: Option<&'static str>;
+
+    /// Returns the RemixIcon label of this icon, e.g. `arrow-up-s-line`.
+    fn label(self) -> &'static str;
+
+    /// Returns the module and feature name of this icon's category, e.g. `arrows`.
+    fn category(self) -> &'static str;
+
+    /// Looks up an icon by its bare RemixIcon label.
+    fn from_label(label: &str) -> Option<Self>;
+}
+
+/// Splits an optionally qualified label (`category/label`) into its category and label.
+fn split_qualified(label: &str) -> (Option<&str>, &str) {
+    match label.split_once('/') {
+        Some((category, label)) => (Some(category), label),
+        None => (None, label),
+    }
+}
+
+/// Returns the category that owns `label`, whether or not its feature is enabled.
+fn owning_category(label: &str) -> Option<&'static str> {
+    CATEGORY_LABELS
+        .iter()
+        .find(|(_, labels)| labels.binary_search(&label).is_ok())
+        .map(|(category, _)| *category)
+}
+
+/// Parses a label into an icon, accepting the `category/label` form only if `allow_qualified`.
+pub(crate) fn parse<T: Labeled>(value: &str, allow_qualified: bool) -> Result<T, String> {
+    let (qualifier, label) = split_qualified(value);
+    if qualifier.is_some() && !allow_qualified {
+        return Err(format!(
+            "qualified RemixIcon label `{value}` is only accepted by \
+             `gpui_remixicon::label::qualified`"
+        ));
+    }
+    let Some(category) = owning_category(label) else {
+        return Err(format!("unknown RemixIcon label `{value}`"));
+    };
+    if let Some(qualifier) = qualifier.filter(|q| *q != category) {
+        return Err(format!(
+            "RemixIcon label `{label}` belongs to the `{category}` category, not `{qualifier}`"
+        ));
+    }
+    if let Some(expected) = T::CATEGORY.filter(|e| *e != category) {
+        return Err(format!(
+            "RemixIcon label `{label}` belongs to the `{category}` category, expected `{expected}`"
+        ));
+    }
+    T::from_label(label).ok_or_else(|| {
+        format!(
+            "RemixIcon label `{label}` belongs to the `{category}` category, \
+             which requires the `{category}` feature of gpui-remixicon"
+        )
+    })
+}
+
+pub(crate) fn deserialize<'de, D, T>(deserializer: D, allow_qualified: bool) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Labeled,
+{
+    use serde::{Deserialize as _, de::Error as _};
+
+    let value = String::deserialize(deserializer)?;
+    parse(&value, allow_qualified).map_err(D::Error::custom)
+}
+
+/// Serializes icons as `category/label` and deserializes them from either that or the bare
+/// label, for use with `#[serde(with = "gpui_remixicon::label::qualified")]`.
+pub mod qualified {
+    use super::Labeled;
+
+    /// Serializes `icon` as its category-qualified label, e.g. `arrows/arrow-up-s-line`.
+    pub fn serialize<T, S>(icon: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Labeled,
+        S: serde::Serializer,
+    {
+        serializer.collect_str(&format_args!("{}/{}", icon.category(), icon.label()))
+    }
+
+    /// Deserializes an icon from its bare or category-qualified label.
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: Labeled,
+        D: serde::Deserializer<'de>,
+    {
+        super::deserialize(deserializer, true)
+    }
+}
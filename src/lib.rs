@@ -3,8 +3,10 @@
 //! Each icon category is available as a module (e.g. `arrows`, `system`), and each module exposes
 //! an `Icon` enum that implements [`RemixIcon`].
 //!
-//! Category module docs include a mapping table from RemixIcon labels (e.g. `arrow-up-s-line`) to
-//! Rust variants (e.g. [`arrows::Icon::ArrowUpSLine`]).
+//! Each variant documents its RemixIcon label (e.g. `arrow-up-s-line` for
+//! [`arrows::Icon::ArrowUpSLine`]). Category icons convert into the flat [`RemixIconName`] with
+//! [`From`], and back with [`TryFrom`]; [`RemixIconName::split`] returns the [`Category`] and the
+//! icon's discriminant within it.
 //!
 //! ```
 //! use gpui_remixicon::{Icon, arrows};
@@ -12,9 +14,73 @@
 //! let up = Icon::new(arrows::Icon::ArrowUpSLine);
 //! ```
 
+mod button;
+#[cfg(feature = "arrows")]
+mod compass;
+mod defaults;
+#[cfg(feature = "document")]
+mod file_icons;
 mod icon;
+mod icon_label;
+#[cfg(all(
+    feature = "arrows",
+    feature = "development",
+    feature = "editor",
+    feature = "system"
+))]
+mod keys;
+#[cfg(feature = "serde")]
+pub mod label;
+#[cfg(feature = "editor")]
+mod locale;
+#[cfg(all(
+    feature = "gpui-component",
+    feature = "arrows",
+    feature = "buildings",
+    feature = "business",
+    feature = "design",
+    feature = "development",
+    feature = "document",
+    feature = "editor",
+    feature = "health_and_medical",
+    feature = "logos",
+    feature = "map",
+    feature = "media",
+    feature = "system",
+    feature = "user_and_faces",
+    feature = "weather",
+))]
+pub mod lucide;
+#[cfg(any(feature = "document", feature = "editor", feature = "weather"))]
+mod sorted;
+#[cfg(feature = "system")]
+mod status;
+#[cfg(feature = "test-support")]
+pub mod test_support;
+mod theme;
+mod view;
+#[cfg(feature = "weather")]
+mod weather_condition;
 
+pub use button::{IconButton, IconToggle};
+pub use defaults::{IconDefaults, IconSizes, IconStyle, IconStyleScope, icon_style_scope};
+#[cfg(feature = "document")]
+pub use file_icons::{FileIcons, file_icon, mime_icon, mime_type};
 pub use icon::{Icon, IconNamed, Sizable, Size};
+pub use icon_label::{IconGap, IconLabel, IconPosition};
+#[cfg(all(
+    feature = "arrows",
+    feature = "development",
+    feature = "editor",
+    feature = "system"
+))]
+pub use keys::{KeyBinding, KeyGlyph, KeyPlatform, key_icon, key_icon_for};
+#[cfg(feature = "editor")]
+pub use locale::{language_name, locale_label, locale_name};
+#[cfg(feature = "system")]
+pub use status::{Status, StatusIcon, StatusIcons};
+pub use theme::{IconRole, IconTheme};
+pub use view::IconView;
 
 use gpui::SharedString;
 
@@ -24,6 +90,15 @@ pub trait RemixIcon {
     fn path(self) -> SharedString;
 }
 
+/// Trait for style-agnostic icon names, drawn outlined or filled depending on the preferred
+/// [`IconStyle`].
+///
+/// Implemented by every category's generated `IconBase` enum; see [`Icon::named`].
+pub trait RemixIconBase {
+    /// Returns the asset path of the icon drawn in `style`.
+    fn path(self, style: IconStyle) -> SharedString;
+}
+
 // Include generated icon names (category modules and RemixIconName enum)
 include!(concat!(env!("OUT_DIR"), "/icon_name.rs"));
 
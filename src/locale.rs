@@ -0,0 +1,118 @@
+//! Locale switchers.
+
+use crate::sorted::is_strictly_sorted;
+use crate::{IconLabel, editor};
+
+/// Native names of languages by their lowercase ISO 639 code, sorted for [`language_name`].
+const LANGUAGES: &[(&str, &str)] = &[
+    ("ar", "العربية"),
+    ("bg", "Български"),
+    ("bn", "বাংলা"),
+    ("ca", "Català"),
+    ("cs", "Čeština"),
+    ("da", "Dansk"),
+    ("de", "Deutsch"),
+    ("el", "Ελληνικά"),
+    ("en", "English"),
+    ("es", "Español"),
+    ("et", "Eesti"),
+    ("fa", "فارسی"),
+    ("fi", "Suomi"),
+    ("fil", "Filipino"),
+    ("fr", "Français"),
+    ("he", "עברית"),
+    ("hi", "हिन्दी"),
+    ("hr", "Hrvatski"),
+    ("hu", "Magyar"),
+    ("id", "Bahasa Indonesia"),
+    ("it", "Italiano"),
+    ("ja", "日本語"),
+    ("ko", "한국어"),
+    ("lt", "Lietuvių"),
+    ("lv", "Latviešu"),
+    ("ms", "Bahasa Melayu"),
+    ("nb", "Norsk bokmål"),
+    ("nl", "Nederlands"),
+    ("nn", "Norsk nynorsk"),
+    ("no", "Norsk"),
+    ("pl", "Polski"),
+    ("pt", "Português"),
+    ("ro", "Română"),
+    ("ru", "Русский"),
+    ("sk", "Slovenčina"),
+    ("sl", "Slovenščina"),
+    ("sr", "Српски"),
+    ("sv", "Svenska"),
+    ("sw", "Kiswahili"),
+    ("ta", "தமிழ்"),
+    ("th", "ไทย"),
+    ("tr", "Türkçe"),
+    ("uk", "Українська"),
+    ("ur", "اردو"),
+    ("vi", "Tiếng Việt"),
+    ("zh", "中文"),
+];
+
+const _: () = assert!(
+    is_strictly_sorted(LANGUAGES),
+    "LANGUAGES must be sorted and free of duplicates"
+);
+
+/// Returns the native name of the language of a BCP 47 tag, e.g. `"Deutsch"` for `"de-AT"`, or
+/// `None` for languages without a built-in name.
+pub fn language_name(tag: &str) -> Option<&'static str> {
+    let language = subtags(tag).next()?.to_ascii_lowercase();
+    LANGUAGES
+        .binary_search_by_key(&language.as_str(), |(code, _)| code)
+        .ok()
+        .map(|index| LANGUAGES[index].1)
+}
+
+/// Returns the name of a locale for a locale switcher: the native name of its language, followed
+/// by its region if the tag has one, e.g. `"English (US)"` for `"en-US"` or `"Português (BR)"`
+/// for `"pt_BR"`. Tags of unknown languages are returned as is.
+pub fn locale_name(tag: &str) -> String {
+    match language_name(tag) {
+        Some(name) => match region(tag) {
+            Some(region) => format!("{} ({})", name, region),
+            None => name.to_string(),
+        },
+        None => tag.to_string(),
+    }
+}
+
+/// Returns a label for a locale switcher: the translate icon next to the
+/// [`locale_name`] of `tag`.
+///
+/// RemixIcon has no country flags, so every locale shares the same icon. It is the `editor`
+/// category's `Translate2` rather than a globe: RemixIcon files `GlobalLine` under `business`, not
+/// `map`, and a translation mark reads as "language" where a globe reads as "region" or "web".
+///
+/// ```
+/// use gpui_remixicon::locale_label;
+///
+/// let item = locale_label("ja-JP").truncate();
+/// ```
+pub fn locale_label(tag: &str) -> IconLabel {
+    IconLabel::new(editor::Icon::Translate2, locale_name(tag))
+}
+
+fn subtags(tag: &str) -> impl Iterator<Item = &str> {
+    tag.split(['-', '_']).filter(|subtag| !subtag.is_empty())
+}
+
+/// Returns the uppercase region subtag of `tag`: two letters or three digits following the
+/// language, its optional three-letter extended language subtags and an optional four-letter
+/// script.
+fn region(tag: &str) -> Option<String> {
+    let is_alphabetic =
+        |subtag: &str, len| subtag.len() == len && subtag.chars().all(|c| c.is_ascii_alphabetic());
+    subtags(tag)
+        .skip(1)
+        .find(|subtag| !is_alphabetic(subtag, 3) && !is_alphabetic(subtag, 4))
+        .filter(|subtag| {
+            is_alphabetic(subtag, 2)
+                || (subtag.len() == 3 && subtag.chars().all(|c| c.is_ascii_digit()))
+        })
+        .map(str::to_ascii_uppercase)
+}
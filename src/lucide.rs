@@ -0,0 +1,256 @@
+//! Migration helpers from gpui-component's Lucide [`IconName`] set to RemixIcon.
+//!
+//! Components inside gpui-component hard-code [`IconName`] values. Use [`remix_equivalent`] to
+//! convert them in your own code, and [`LucideAssets`] to make gpui-component itself render the
+//! RemixIcon equivalents, so the whole UI uses one icon family.
+//!
+//! ```ignore
+//! use gpui::*;
+//! use gpui_remixicon::{Assets, lucide::LucideAssets};
+//!
+//! let app = Application::new().with_assets(LucideAssets(Assets));
+//! ```
+
+use crate::{RemixIcon, RemixIconName};
+use gpui::{AssetSource, Result, SharedString};
+use gpui_component::{IconName, IconNamed as _};
+use std::borrow::Cow;
+use std::sync::LazyLock;
+
+/// Every gpui-component [`IconName`] variant.
+const ICON_NAMES: [IconName; 86] = [
+    IconName::ALargeSmall,
+    IconName::ArrowDown,
+    IconName::ArrowLeft,
+    IconName::ArrowRight,
+    IconName::ArrowUp,
+    IconName::Asterisk,
+    IconName::Bell,
+    IconName::BookOpen,
+    IconName::Bot,
+    IconName::Building2,
+    IconName::Calendar,
+    IconName::CaseSensitive,
+    IconName::ChartPie,
+    IconName::Check,
+    IconName::ChevronDown,
+    IconName::ChevronLeft,
+    IconName::ChevronRight,
+    IconName::ChevronsUpDown,
+    IconName::ChevronUp,
+    IconName::CircleCheck,
+    IconName::CircleUser,
+    IconName::CircleX,
+    IconName::Close,
+    IconName::Copy,
+    IconName::Dash,
+    IconName::Delete,
+    IconName::Ellipsis,
+    IconName::EllipsisVertical,
+    IconName::ExternalLink,
+    IconName::Eye,
+    IconName::EyeOff,
+    IconName::File,
+    IconName::Folder,
+    IconName::FolderClosed,
+    IconName::FolderOpen,
+    IconName::Frame,
+    IconName::GalleryVerticalEnd,
+    IconName::GitHub,
+    IconName::Globe,
+    IconName::Heart,
+    IconName::HeartOff,
+    IconName::Inbox,
+    IconName::Info,
+    IconName::Inspector,
+    IconName::LayoutDashboard,
+    IconName::Loader,
+    IconName::LoaderCircle,
+    IconName::Map,
+    IconName::Maximize,
+    IconName::Menu,
+    IconName::Minimize,
+    IconName::Minus,
+    IconName::Moon,
+    IconName::Palette,
+    IconName::PanelBottom,
+    IconName::PanelBottomOpen,
+    IconName::PanelLeft,
+    IconName::PanelLeftClose,
+    IconName::PanelLeftOpen,
+    IconName::PanelRight,
+    IconName::PanelRightClose,
+    IconName::PanelRightOpen,
+    IconName::Plus,
+    IconName::Redo,
+    IconName::Redo2,
+    IconName::Replace,
+    IconName::ResizeCorner,
+    IconName::Search,
+    IconName::Settings,
+    IconName::Settings2,
+    IconName::SortAscending,
+    IconName::SortDescending,
+    IconName::SquareTerminal,
+    IconName::Star,
+    IconName::StarOff,
+    IconName::Sun,
+    IconName::ThumbsDown,
+    IconName::ThumbsUp,
+    IconName::TriangleAlert,
+    IconName::Undo,
+    IconName::Undo2,
+    IconName::User,
+    IconName::WindowClose,
+    IconName::WindowMaximize,
+    IconName::WindowMinimize,
+    IconName::WindowRestore,
+];
+
+/// Returns the RemixIcon that replaces a gpui-component Lucide icon.
+///
+/// Outlined (`*Line`) variants are preferred, matching Lucide's stroke style.
+pub fn remix_equivalent(name: IconName) -> RemixIconName {
+    use RemixIconName::*;
+
+    match name {
+        IconName::ALargeSmall => EditorFontSize,
+        IconName::ArrowDown => ArrowsArrowDownLine,
+        IconName::ArrowLeft => ArrowsArrowLeftLine,
+        IconName::ArrowRight => ArrowsArrowRightLine,
+        IconName::ArrowUp => ArrowsArrowUpLine,
+        IconName::Asterisk => EditorAsterisk,
+        IconName::Bell => MediaNotification3Line,
+        IconName::BookOpen => DocumentBookOpenLine,
+        IconName::Bot => UserFacesRobot2Line,
+        IconName::Building2 => BuildingsBuilding2Line,
+        IconName::Calendar => BusinessCalendarLine,
+        IconName::CaseSensitive => EditorFontSize2,
+        IconName::ChartPie => BusinessPieChartLine,
+        IconName::Check => SystemCheckLine,
+        IconName::ChevronDown => ArrowsArrowDownSLine,
+        IconName::ChevronLeft => ArrowsArrowLeftSLine,
+        IconName::ChevronRight => ArrowsArrowRightSLine,
+        IconName::ChevronsUpDown => ArrowsExpandUpDownLine,
+        IconName::ChevronUp => ArrowsArrowUpSLine,
+        IconName::CircleCheck => SystemCheckboxCircleLine,
+        IconName::CircleUser => UserFacesAccountCircleLine,
+        IconName::CircleX => SystemCloseCircleLine,
+        IconName::Close => SystemCloseLine,
+        IconName::Copy => DocumentFileCopyLine,
+        IconName::Dash => SystemSubtractLine,
+        IconName::Delete => SystemDeleteBack2Line,
+        IconName::Ellipsis => SystemMoreLine,
+        IconName::EllipsisVertical => SystemMore2Line,
+        IconName::ExternalLink => SystemExternalLinkLine,
+        IconName::Eye => SystemEyeLine,
+        IconName::EyeOff => SystemEyeOffLine,
+        IconName::File => DocumentFileLine,
+        IconName::Folder => DocumentFolderLine,
+        IconName::FolderClosed => DocumentFolderLine,
+        IconName::FolderOpen => DocumentFolderOpenLine,
+        IconName::Frame => DesignArtboardLine,
+        IconName::GalleryVerticalEnd => BusinessStackLine,
+        IconName::GitHub => LogosGithubLine,
+        IconName::Globe => BusinessGlobalLine,
+        IconName::Heart => HealthMedicalHeartLine,
+        IconName::HeartOff => HealthMedicalDislikeLine,
+        IconName::Inbox => BusinessInboxLine,
+        IconName::Info => SystemInformationLine,
+        IconName::Inspector => DevelopmentCursorLine,
+        IconName::LayoutDashboard => SystemDashboardLine,
+        IconName::Loader => SystemLoaderLine,
+        IconName::LoaderCircle => SystemLoader4Line,
+        IconName::Map => MapMapLine,
+        IconName::Maximize => MediaFullscreenLine,
+        IconName::Menu => SystemMenuLine,
+        IconName::Minimize => MediaFullscreenExitLine,
+        IconName::Minus => SystemSubtractLine,
+        IconName::Moon => WeatherMoonLine,
+        IconName::Palette => DesignPaletteLine,
+        IconName::PanelBottom => DesignLayoutBottomLine,
+        IconName::PanelBottomOpen => DesignLayoutBottom2Line,
+        IconName::PanelLeft => DesignLayoutLeftLine,
+        IconName::PanelLeftClose => SystemSidebarFoldLine,
+        IconName::PanelLeftOpen => SystemSidebarUnfoldLine,
+        IconName::PanelRight => DesignLayoutRightLine,
+        IconName::PanelRightClose => DesignLayoutRight2Line,
+        IconName::PanelRightOpen => DesignLayoutRightLine,
+        IconName::Plus => SystemAddLine,
+        IconName::Redo => ArrowsArrowGoForwardLine,
+        IconName::Redo2 => ArrowsCornerUpRightLine,
+        IconName::Replace => SystemFindReplaceLine,
+        IconName::ResizeCorner => ArrowsExpandDiagonalSLine,
+        IconName::Search => SystemSearchLine,
+        IconName::Settings => SystemSettings3Line,
+        IconName::Settings2 => MediaEqualizerLine,
+        IconName::SortAscending => EditorSortAsc,
+        IconName::SortDescending => EditorSortDesc,
+        IconName::SquareTerminal => DevelopmentTerminalBoxLine,
+        IconName::Star => SystemStarLine,
+        IconName::StarOff => SystemStarOffLine,
+        IconName::Sun => WeatherSunLine,
+        IconName::ThumbsDown => SystemThumbDownLine,
+        IconName::ThumbsUp => SystemThumbUpLine,
+        IconName::TriangleAlert => SystemAlertLine,
+        IconName::Undo => ArrowsArrowGoBackLine,
+        IconName::Undo2 => ArrowsCornerUpLeftLine,
+        IconName::User => UserFacesUserLine,
+        IconName::WindowClose => SystemCloseLine,
+        IconName::WindowMaximize => SystemCheckboxBlankLine,
+        IconName::WindowMinimize => SystemSubtractLine,
+        IconName::WindowRestore => SystemCheckboxMultipleBlankLine,
+    }
+}
+
+/// Paths of every [`ICON_NAMES`] entry, sorted for binary search.
+fn paths() -> &'static [(SharedString, IconName)] {
+    static PATHS: LazyLock<Vec<(SharedString, IconName)>> = LazyLock::new(|| {
+        let mut paths: Vec<_> = ICON_NAMES
+            .into_iter()
+            .map(|name| (name.clone().path(), name))
+            .collect();
+        paths.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        paths
+    });
+    &PATHS
+}
+
+/// Returns the Lucide icon gpui-component loads from `path` (e.g. `icons/chevron-down.svg`).
+pub fn lucide_icon_for_path(path: &str) -> Option<IconName> {
+    let paths = paths();
+    paths
+        .binary_search_by(|(candidate, _)| candidate.as_ref().cmp(path))
+        .ok()
+        .map(|index| paths[index].1.clone())
+}
+
+/// Returns the path gpui-component loads every Lucide icon from.
+pub fn lucide_paths() -> impl Iterator<Item = &'static SharedString> {
+    paths().iter().map(|(path, _)| path)
+}
+
+/// An `AssetSource` that serves RemixIcon SVGs in place of gpui-component's Lucide icons.
+///
+/// Requests for gpui-component icon paths are answered with the [`remix_equivalent`] from
+/// [`Assets`](crate::Assets); every other path is delegated to the wrapped source.
+pub struct LucideAssets<A>(pub A);
+
+impl<A: AssetSource> AssetSource for LucideAssets<A> {
+    fn load(&self, path: &str) -> Result<Option<Cow<'static, [u8]>>> {
+        match lucide_icon_for_path(path) {
+            Some(name) => crate::Assets.load(&remix_equivalent(name).path()),
+            None => self.0.load(path),
+        }
+    }
+
+    fn list(&self, path: &str) -> Result<Vec<SharedString>> {
+        let mut paths = self.0.list(path)?;
+        for lucide_path in lucide_paths() {
+            if lucide_path.starts_with(path) && !paths.contains(lucide_path) {
+                paths.push(lucide_path.clone());
+            }
+        }
+        Ok(paths)
+    }
+}
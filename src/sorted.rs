@@ -0,0 +1,29 @@
+//! Compile-time checks of the lookup tables searched with binary search.
+
+/// Whether the keys of `table` are strictly ascending, i.e. sorted and free of duplicates.
+///
+/// Assert it next to a table with `const _: () = assert!(...)`, so an out-of-order entry fails
+/// the build instead of silently breaking lookups.
+pub(crate) const fn is_strictly_sorted<T>(table: &[(&str, T)]) -> bool {
+    let mut i = 1;
+    while i < table.len() {
+        if !is_less(table[i - 1].0, table[i].0) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// `a < b` in the byte order `str` sorts by.
+const fn is_less(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
@@ -0,0 +1,204 @@
+//! Status indicators.
+
+use crate::view::spin;
+use crate::{Icon, IconRole, Sizable, Size, system};
+use gpui::{
+    App, ElementId, Global, InteractiveElement as _, IntoElement, ParentElement as _, RenderOnce,
+    Styled as _, Window, div, ease_out_quint, prelude::FluentBuilder as _,
+};
+use std::time::Duration;
+
+/// Length of the fade between two states of a [`StatusIcon`] with
+/// [`transition`](StatusIcon::transition) enabled.
+const TRANSITION_DURATION: Duration = Duration::from_millis(200);
+
+/// The state a [`StatusIcon`] shows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// Nothing has happened yet.
+    #[default]
+    Idle,
+    /// Work in progress; the icon spins.
+    Loading,
+    /// Finished successfully.
+    Success,
+    /// Finished, but needs attention.
+    Warning,
+    /// Failed.
+    Error,
+}
+
+impl Status {
+    /// Every status, in declaration order.
+    pub const ALL: [Status; 5] = [
+        Status::Idle,
+        Status::Loading,
+        Status::Success,
+        Status::Warning,
+        Status::Error,
+    ];
+
+    /// Returns the name of the status, used as the default accessible label of its icon.
+    pub const fn label(self) -> &'static str {
+        match self {
+            Status::Idle => "Idle",
+            Status::Loading => "Loading",
+            Status::Success => "Success",
+            Status::Warning => "Warning",
+            Status::Error => "Error",
+        }
+    }
+}
+
+/// The icon drawn for each [`Status`], colors included.
+///
+/// Install with `cx.set_global(StatusIcons { .. })` to change the icons or colors of every
+/// [`StatusIcon`]. The defaults are `system` icons colored by [`IconRole`], so they follow the
+/// [`IconTheme`](crate::IconTheme).
+#[derive(Clone)]
+pub struct StatusIcons {
+    /// [`Status::Idle`], a muted `CheckboxBlankCircleLine` by default.
+    pub idle: Icon,
+    /// [`Status::Loading`], an accent `Loader4Line` by default.
+    pub loading: Icon,
+    /// [`Status::Success`], a success `CheckboxCircleFill` by default.
+    pub success: Icon,
+    /// [`Status::Warning`], a warning `AlertFill` by default.
+    pub warning: Icon,
+    /// [`Status::Error`], a danger `ErrorWarningFill` by default.
+    pub error: Icon,
+}
+
+impl Global for StatusIcons {}
+
+impl Default for StatusIcons {
+    fn default() -> Self {
+        Self {
+            idle: Self::default_icon(Status::Idle),
+            loading: Self::default_icon(Status::Loading),
+            success: Self::default_icon(Status::Success),
+            warning: Self::default_icon(Status::Warning),
+            error: Self::default_icon(Status::Error),
+        }
+    }
+}
+
+impl StatusIcons {
+    /// Returns the app's status icons, or the defaults if none are installed.
+    pub fn global(cx: &App) -> Self {
+        cx.try_global::<Self>().cloned().unwrap_or_default()
+    }
+
+    /// Returns the icon the app draws for `status`, without copying the other statuses' icons.
+    pub fn resolve(status: Status, cx: &App) -> Icon {
+        match cx.try_global::<Self>() {
+            Some(icons) => icons.icon(status).clone(),
+            None => Self::default_icon(status),
+        }
+    }
+
+    /// The default icon of `status`.
+    fn default_icon(status: Status) -> Icon {
+        let (icon, role) = match status {
+            Status::Idle => (system::Icon::CheckboxBlankCircleLine, IconRole::Muted),
+            Status::Loading => (system::Icon::Loader4Line, IconRole::Accent),
+            Status::Success => (system::Icon::CheckboxCircleFill, IconRole::Success),
+            Status::Warning => (system::Icon::AlertFill, IconRole::Warning),
+            Status::Error => (system::Icon::ErrorWarningFill, IconRole::Danger),
+        };
+        Icon::new(icon).role(role).aria_label(status.label())
+    }
+
+    /// Returns the icon drawn for `status`.
+    pub fn icon(&self, status: Status) -> &Icon {
+        match status {
+            Status::Idle => &self.idle,
+            Status::Loading => &self.loading,
+            Status::Success => &self.success,
+            Status::Warning => &self.warning,
+            Status::Error => &self.error,
+        }
+    }
+}
+
+/// An icon showing a [`Status`], e.g. of a sync or a build.
+///
+/// The icon and color of each status come from the [`StatusIcons`] global. The loading icon
+/// spins, and with [`transition`](Self::transition) the icon fades in whenever the status
+/// changes.
+///
+/// ```
+/// use gpui_remixicon::{Status, StatusIcon};
+///
+/// let sync = StatusIcon::new("sync-status", Status::Loading).transition(true);
+/// ```
+#[derive(IntoElement)]
+pub struct StatusIcon {
+    id: ElementId,
+    status: Status,
+    size: Option<Size>,
+    transition: bool,
+}
+
+impl StatusIcon {
+    /// Create an indicator showing `status`.
+    pub fn new(id: impl Into<ElementId>, status: Status) -> Self {
+        Self {
+            id: id.into(),
+            status,
+            size: None,
+            transition: false,
+        }
+    }
+
+    /// Fade the icon in when the status changes.
+    pub fn transition(mut self, transition: bool) -> Self {
+        self.transition = transition;
+        self
+    }
+}
+
+impl Sizable for StatusIcon {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+}
+
+impl RenderOnce for StatusIcon {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let status = self.status;
+
+        // The last status shown, and when it replaced another one, so the first status appears
+        // without a transition
+        let shown = window.use_keyed_state(self.id.clone(), cx, |_, _| (status, None));
+        // The executor's clock, which tests can advance
+        let now = cx.background_executor().now();
+        let changed_at = shown.update(cx, |shown, _| {
+            if shown.0 != status {
+                *shown = (status, Some(now));
+            }
+            shown.1
+        });
+        let opacity = match changed_at.filter(|_| self.transition) {
+            Some(changed_at) => {
+                let progress = now.saturating_duration_since(changed_at).as_secs_f32()
+                    / TRANSITION_DURATION.as_secs_f32();
+                if progress < 1. {
+                    window.request_animation_frame();
+                }
+                ease_out_quint()(progress.min(1.))
+            }
+            None => 1.,
+        };
+
+        let icon = StatusIcons::resolve(status, cx)
+            .when_some(self.size, |icon, size| icon.with_size(size))
+            .when(opacity < 1., |icon| icon.opacity(opacity));
+        let icon = match status {
+            Status::Loading => spin(icon, "spin").into_any_element(),
+            _ => icon.into_any_element(),
+        };
+        div().id(self.id).flex().flex_none().child(icon)
+    }
+}
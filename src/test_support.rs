@@ -0,0 +1,333 @@
+//! Headless rendering of [`Icon`]s for tests.
+//!
+//! Icons drawn in a GPUI test window (see [`open_window`]) record what GPUI laid out and painted:
+//! their bounds, the color their computed style fills the SVG with, including hover, active,
+//! group-hover and disabled states, and their transformation. [`draw_icons`] returns the icons
+//! of a fresh frame, [`icons_drawn_by`] those of the frames an update caused.
+//!
+//! [`rasterize`] draws a recorded icon the way GPUI's SVG renderer does (the SVG is used as an
+//! alpha mask filled with the icon color) using a CPU renderer, so snapshots run on machines
+//! without a GPU. [`assert_snapshot`] compares the result against a committed PNG; set
+//! `UPDATE_SNAPSHOTS=1` to write new golden images instead.
+
+use crate::{Assets, Icon};
+use gpui::{
+    AnyElement, App, AssetSource as _, Bounds, Element, ElementId, GlobalElementId, Hitbox, Hsla,
+    InspectorElementId, InteractiveElement, IntoElement, LayoutId, Pixels, Radians, Render, Rgba,
+    SharedString, Size, TestAppContext, Transformation, TransformationMatrix, VisualTestContext,
+    Window, point, radians, size,
+};
+use resvg::tiny_skia::{PremultipliedColorU8, Transform};
+use resvg::usvg;
+use std::cell::RefCell;
+use std::path::Path;
+
+pub use resvg::tiny_skia::Pixmap;
+
+/// Maximum per-channel difference for two pixels to be considered equal.
+const CHANNEL_TOLERANCE: u8 = 16;
+
+/// Maximum fraction of pixels allowed to differ beyond [`CHANNEL_TOLERANCE`].
+const MAX_DIFFERENT_PIXELS: f32 = 0.005;
+
+/// An icon as GPUI drew it in a test window.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DrawnIcon {
+    /// Asset path of the drawn SVG.
+    pub path: SharedString,
+    /// Bounds GPUI laid the icon out in, in window coordinates.
+    pub bounds: Bounds<Pixels>,
+    /// Color GPUI filled the SVG with, including the icon's own opacity.
+    pub color: Hsla,
+    /// The transformation the SVG was drawn with.
+    pub transformation: Option<Transformation>,
+    rotation: Radians,
+    scale: Size<f32>,
+}
+
+/// Icons drawn since the last [`draw_icons`], present while tests record them.
+#[derive(Default)]
+struct DrawnIcons(RefCell<Vec<DrawnIcon>>);
+
+impl gpui::Global for DrawnIcons {}
+
+/// Wraps the svg element of an icon to record what GPUI draws for it.
+struct Probe<E> {
+    element: E,
+    path: SharedString,
+    transformation: Option<Transformation>,
+    rotation: Radians,
+    scale: Size<f32>,
+}
+
+/// Erase the type of the rendered svg of `icon`, wrapping it in a [`Probe`] while recording.
+pub(crate) fn probe<E>(icon: &Icon, element: E, cx: &App) -> AnyElement
+where
+    E: Element<PrepaintState = Option<Hitbox>> + InteractiveElement,
+{
+    if !cx.has_global::<DrawnIcons>() {
+        return element.into_any_element();
+    }
+    let flip = |flipped| if flipped { -1. } else { 1. };
+    Probe {
+        element,
+        path: icon.resolved_path(cx),
+        transformation: icon.transformation(),
+        rotation: icon.rotation.unwrap_or(radians(0.)),
+        scale: size(flip(icon.flip_horizontal), flip(icon.flip_vertical)),
+    }
+    .into_any_element()
+}
+
+impl<E> IntoElement for Probe<E>
+where
+    E: Element<PrepaintState = Option<Hitbox>> + InteractiveElement,
+{
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl<E> Element for Probe<E>
+where
+    E: Element<PrepaintState = Option<Hitbox>> + InteractiveElement,
+{
+    type RequestLayoutState = E::RequestLayoutState;
+    type PrepaintState = Option<Hitbox>;
+
+    fn id(&self) -> Option<ElementId> {
+        Element::id(&self.element)
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        self.element.source_location()
+    }
+
+    fn request_layout(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        self.element
+            .request_layout(global_id, inspector_id, window, cx)
+    }
+
+    fn prepaint(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Option<Hitbox> {
+        self.element
+            .prepaint(global_id, inspector_id, bounds, request_layout, window, cx)
+    }
+
+    fn paint(
+        &mut self,
+        global_id: Option<&GlobalElementId>,
+        inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        request_layout: &mut Self::RequestLayoutState,
+        hitbox: &mut Option<Hitbox>,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.element.paint(
+            global_id,
+            inspector_id,
+            bounds,
+            request_layout,
+            hitbox,
+            window,
+            cx,
+        );
+
+        // The style the svg was just painted with, refined by its interaction states
+        let style =
+            self.element
+                .interactivity()
+                .compute_style(global_id, hitbox.as_ref(), window, cx);
+        let color = style
+            .text
+            .color
+            .unwrap_or_default()
+            .opacity(style.opacity.unwrap_or(1.));
+        cx.global::<DrawnIcons>().0.borrow_mut().push(DrawnIcon {
+            path: self.path.clone(),
+            bounds,
+            color,
+            transformation: self.transformation,
+            rotation: self.rotation,
+            scale: self.scale,
+        });
+    }
+}
+
+/// Builds the root element of a test window.
+type RenderFn = Box<dyn Fn(&mut Window, &mut App) -> AnyElement>;
+
+/// Renders the element returned by its closure, see [`open_window`].
+struct TestView(RenderFn);
+
+impl Render for TestView {
+    fn render(&mut self, window: &mut Window, cx: &mut gpui::Context<Self>) -> impl IntoElement {
+        (self.0)(window, cx)
+    }
+}
+
+/// Open a test window whose root view renders the element returned by `render`.
+pub fn open_window<E: IntoElement>(
+    cx: &mut TestAppContext,
+    render: impl Fn(&mut Window, &mut App) -> E + 'static,
+) -> &mut VisualTestContext {
+    let view = TestView(Box::new(move |window, cx| {
+        render(window, cx).into_any_element()
+    }));
+    cx.add_window_view(|_, _| view).1
+}
+
+/// Redraw the window of `cx` and return every icon drawn, in paint order.
+pub fn draw_icons(cx: &mut VisualTestContext) -> Vec<DrawnIcon> {
+    icons_drawn_by(cx, |cx| cx.update(|window, _| window.refresh()))
+}
+
+/// Run `f` and return the icons drawn by the frames it caused, without forcing a redraw.
+///
+/// Returns no icons if `f` didn't invalidate the window, e.g. a view update that forgot to
+/// notify.
+pub fn icons_drawn_by(
+    cx: &mut VisualTestContext,
+    f: impl FnOnce(&mut VisualTestContext),
+) -> Vec<DrawnIcon> {
+    cx.update(|_, cx| cx.default_global::<DrawnIcons>().0.borrow_mut().clear());
+    f(cx);
+    cx.run_until_parked();
+    cx.update(|_, cx| cx.global::<DrawnIcons>().0.take())
+}
+
+/// Draw `icon` alone in a new test window.
+///
+/// Panics unless exactly one icon was drawn.
+pub fn draw_icon(cx: &mut TestAppContext, icon: Icon) -> DrawnIcon {
+    let cx = open_window(cx, move |_, _| icon.clone());
+    let mut icons = draw_icons(cx);
+    assert_eq!(icons.len(), 1, "expected a single icon, drew {icons:?}");
+    icons.remove(0)
+}
+
+/// Rasterize a drawn icon into a pixmap of its size.
+///
+/// Rotation and flips are drawn, but the rest of a custom [`Icon::transform`] is not, since GPUI
+/// doesn't expose the components of a [`Transformation`]; compare
+/// [`DrawnIcon::transformation`] for those.
+///
+/// Panics if the icon's path is not embedded in [`Assets`].
+pub fn rasterize(icon: &DrawnIcon) -> Pixmap {
+    let path = &icon.path;
+    let data = Assets
+        .load(path)
+        .ok()
+        .flatten()
+        .unwrap_or_else(|| panic!("icon asset `{path}` not found"));
+    let tree = usvg::Tree::from_data(&data, &usvg::Options::default())
+        .unwrap_or_else(|err| panic!("icon asset `{path}` is not a valid SVG: {err}"));
+
+    let width = f32::from(icon.bounds.size.width);
+    let height = f32::from(icon.bounds.size.height);
+    let mut mask =
+        Pixmap::new((width.ceil() as u32).max(1), (height.ceil() as u32).max(1)).unwrap();
+
+    // Scale the viewBox to the icon bounds, then rotate and flip around the center with the
+    // matrix GPUI builds from a `Transformation`
+    let center = point(mask.width() as f32 / 2., mask.height() as f32 / 2.).map(Pixels::from);
+    let matrix = TransformationMatrix::unit()
+        .translate(center.scale(1.))
+        .rotate(icon.rotation)
+        .scale(icon.scale)
+        .translate(point(-center.x, -center.y).scale(1.));
+    let [[sx, kx], [ky, sy]] = matrix.rotation_scale;
+    let [tx, ty] = matrix.translation;
+    let transform = Transform::from_row(sx, ky, kx, sy, tx, ty)
+        .pre_scale(width / tree.size().width(), height / tree.size().height());
+    resvg::render(&tree, transform, &mut mask.as_mut());
+
+    // Fill the coverage mask with the icon color
+    let color = Rgba::from(icon.color);
+    for pixel in mask.pixels_mut() {
+        let alpha = pixel.alpha() as f32 / 255. * color.a;
+        let channel = |c: f32| (c * alpha * 255.).round() as u8;
+        *pixel = PremultipliedColorU8::from_rgba(
+            channel(color.r),
+            channel(color.g),
+            channel(color.b),
+            (alpha * 255.).round() as u8,
+        )
+        .unwrap();
+    }
+    mask
+}
+
+/// Compare `actual` against the golden image `<dir>/<name>.png`.
+///
+/// With `UPDATE_SNAPSHOTS=1` the golden image is (re)written instead. On mismatch the actual
+/// image is saved as `<dir>/<name>.actual.png` for inspection.
+pub fn assert_snapshot(dir: impl AsRef<Path>, name: &str, actual: &Pixmap) {
+    let dir = dir.as_ref();
+    let golden_path = dir.join(format!("{name}.png"));
+    let actual_path = dir.join(format!("{name}.actual.png"));
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some_and(|v| v != "0") {
+        std::fs::create_dir_all(dir).unwrap();
+        actual.save_png(&golden_path).unwrap();
+        std::fs::remove_file(&actual_path).ok();
+        return;
+    }
+
+    let Ok(golden) = Pixmap::load_png(&golden_path) else {
+        actual.save_png(&actual_path).ok();
+        panic!(
+            "missing snapshot {}; run with UPDATE_SNAPSHOTS=1 to create it",
+            golden_path.display()
+        );
+    };
+
+    let mismatch = if (golden.width(), golden.height()) != (actual.width(), actual.height()) {
+        Some(format!(
+            "size {}x{} != golden {}x{}",
+            actual.width(),
+            actual.height(),
+            golden.width(),
+            golden.height()
+        ))
+    } else {
+        let different = golden
+            .data()
+            .chunks(4)
+            .zip(actual.data().chunks(4))
+            .filter(|(a, b)| {
+                a.iter()
+                    .zip(b.iter())
+                    .any(|(a, b)| a.abs_diff(*b) > CHANNEL_TOLERANCE)
+            })
+            .count();
+        let fraction = different as f32 / (golden.width() * golden.height()) as f32;
+        (fraction > MAX_DIFFERENT_PIXELS).then(|| format!("{different} pixels differ"))
+    };
+
+    if let Some(mismatch) = mismatch {
+        actual.save_png(&actual_path).ok();
+        panic!(
+            "snapshot {} does not match: {mismatch}; actual image written to {}",
+            golden_path.display(),
+            actual_path.display()
+        );
+    }
+    std::fs::remove_file(&actual_path).ok();
+}
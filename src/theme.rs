@@ -0,0 +1,87 @@
+//! Semantic icon colors.
+
+use gpui::{App, Global, Hsla, hsla};
+
+/// A semantic role an icon's color is resolved from at render time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IconRole {
+    /// De-emphasized icons, e.g. hints and disabled decorations.
+    Muted,
+    /// The primary brand color.
+    Accent,
+    /// Destructive actions and errors.
+    Danger,
+    /// Completed or healthy states.
+    Success,
+    /// States that need attention.
+    Warning,
+}
+
+/// Colors of each [`IconRole`].
+///
+/// Install a theme with `cx.set_global(IconTheme { .. })`. Without one, roles follow
+/// gpui-component's active theme when the `gpui-component` feature is enabled and its theme is
+/// initialized, and [`IconTheme::default`] otherwise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IconTheme {
+    /// Color of [`IconRole::Muted`].
+    pub muted: Hsla,
+    /// Color of [`IconRole::Accent`].
+    pub accent: Hsla,
+    /// Color of [`IconRole::Danger`].
+    pub danger: Hsla,
+    /// Color of [`IconRole::Success`].
+    pub success: Hsla,
+    /// Color of [`IconRole::Warning`].
+    pub warning: Hsla,
+}
+
+impl Global for IconTheme {}
+
+impl Default for IconTheme {
+    fn default() -> Self {
+        Self {
+            muted: hsla(240. / 360., 0.04, 0.46, 1.),
+            accent: hsla(217. / 360., 0.91, 0.60, 1.),
+            danger: hsla(0., 0.84, 0.60, 1.),
+            success: hsla(142. / 360., 0.71, 0.45, 1.),
+            warning: hsla(38. / 360., 0.92, 0.50, 1.),
+        }
+    }
+}
+
+impl IconTheme {
+    /// Returns the color of `role` in this theme.
+    pub fn color(&self, role: IconRole) -> Hsla {
+        match role {
+            IconRole::Muted => self.muted,
+            IconRole::Accent => self.accent,
+            IconRole::Danger => self.danger,
+            IconRole::Success => self.success,
+            IconRole::Warning => self.warning,
+        }
+    }
+
+    /// Resolves the color of `role` against the app's current theme.
+    pub fn resolve(role: IconRole, cx: &App) -> Hsla {
+        if let Some(theme) = cx.try_global::<IconTheme>() {
+            return theme.color(role);
+        }
+
+        #[cfg(feature = "gpui-component")]
+        if cx.has_global::<gpui_component::Theme>() {
+            use gpui_component::ActiveTheme as _;
+
+            let theme = cx.theme();
+            return match role {
+                IconRole::Muted => theme.muted_foreground,
+                IconRole::Accent => theme.primary,
+                IconRole::Danger => theme.danger,
+                IconRole::Success => theme.success,
+                IconRole::Warning => theme.warning,
+            };
+        }
+
+        Self::default().color(role)
+    }
+}
@@ -0,0 +1,112 @@
+//! Icons that change after they are created.
+
+use crate::{Icon, Sizable as _, Size};
+use gpui::{
+    Animation, AnimationElement, AnimationExt as _, Context, ElementId, Hsla, IntoElement, Render,
+    Styled as _, Window, percentage, prelude::FluentBuilder as _,
+};
+use std::time::Duration;
+
+/// Time a spinning icon takes for one full turn.
+const SPIN_DURATION: Duration = Duration::from_secs(1);
+
+/// A view of an icon that can be updated in place, e.g. a status indicator driven by an async
+/// task.
+///
+/// Every setter re-renders the view, so the parent view doesn't need to be rebuilt. A color or
+/// size set on the view overrides the icon's own and is kept across [`set_icon`](Self::set_icon).
+///
+/// ```
+/// use gpui::{App, AppContext as _, Entity};
+/// use gpui_remixicon::{IconView, system};
+///
+/// fn sync_indicator(cx: &mut App) -> Entity<IconView> {
+///     let view = cx.new(|_| IconView::new(system::Icon::Loader4Line).spinning(true));
+///     view.update(cx, |view, cx| {
+///         view.set_spinning(false, cx);
+///         view.set_icon(system::Icon::CheckLine, cx);
+///     });
+///     view
+/// }
+/// ```
+pub struct IconView {
+    icon: Icon,
+    color: Option<Hsla>,
+    size: Option<Size>,
+    spinning: bool,
+}
+
+impl IconView {
+    /// Create a view drawing `icon`.
+    pub fn new(icon: impl Into<Icon>) -> Self {
+        Self {
+            icon: icon.into(),
+            color: None,
+            size: None,
+            spinning: false,
+        }
+    }
+
+    /// Start the view spinning, e.g. for a loading indicator.
+    pub fn spinning(mut self, spinning: bool) -> Self {
+        self.spinning = spinning;
+        self
+    }
+
+    /// The icon the view draws, without the view's color and size.
+    pub fn icon(&self) -> &Icon {
+        &self.icon
+    }
+
+    /// Whether the icon is spinning.
+    pub fn is_spinning(&self) -> bool {
+        self.spinning
+    }
+
+    /// Replace the icon.
+    pub fn set_icon(&mut self, icon: impl Into<Icon>, cx: &mut Context<Self>) {
+        self.icon = icon.into();
+        cx.notify();
+    }
+
+    /// Set the color of the icon, or `None` to use the icon's own.
+    pub fn set_color(&mut self, color: Option<Hsla>, cx: &mut Context<Self>) {
+        self.color = color;
+        cx.notify();
+    }
+
+    /// Set the size of the icon, or `None` to use the icon's own.
+    pub fn set_size(&mut self, size: Option<Size>, cx: &mut Context<Self>) {
+        self.size = size;
+        cx.notify();
+    }
+
+    /// Start or stop spinning the icon around its center.
+    pub fn set_spinning(&mut self, spinning: bool, cx: &mut Context<Self>) {
+        self.spinning = spinning;
+        cx.notify();
+    }
+}
+
+impl Render for IconView {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let icon = self
+            .icon
+            .clone()
+            .when_some(self.color, |icon, color| icon.text_color(color))
+            .when_some(self.size, |icon, size| icon.with_size(size));
+
+        if self.spinning {
+            spin(icon, "spin").into_any_element()
+        } else {
+            icon.into_any_element()
+        }
+    }
+}
+
+/// Spin `icon` around its center, one turn per [`SPIN_DURATION`].
+pub(crate) fn spin(icon: Icon, id: impl Into<ElementId>) -> AnimationElement<Icon> {
+    icon.with_animation(id, Animation::new(SPIN_DURATION).repeat(), |icon, delta| {
+        icon.rotate(percentage(delta))
+    })
+}
@@ -0,0 +1,235 @@
+//! Icons for weather conditions, re-exported from the `weather` module.
+
+use crate::sorted::is_strictly_sorted;
+use crate::weather::Icon;
+
+/// A weather condition as reported by a forecast, e.g. a [WMO weather code] or a provider's
+/// condition string.
+///
+/// [WMO weather code]: https://open-meteo.com/en/docs#weather_variable_documentation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeatherCondition {
+    /// Clear sky, or sunny.
+    Clear,
+    /// Mainly clear, a few clouds.
+    MainlyClear,
+    /// Partly cloudy.
+    PartlyCloudy,
+    /// Overcast, or mostly cloudy.
+    Overcast,
+    /// Fog, including depositing rime fog.
+    Fog,
+    /// Mist.
+    Mist,
+    /// Haze or smoke.
+    Haze,
+    /// Dust or sand in the air.
+    Dust,
+    /// Drizzle.
+    Drizzle,
+    /// Freezing drizzle.
+    FreezingDrizzle,
+    /// Rain, light or moderate.
+    Rain,
+    /// Heavy rain.
+    HeavyRain,
+    /// Freezing rain.
+    FreezingRain,
+    /// Rain showers, light or moderate.
+    Showers,
+    /// Violent rain showers.
+    HeavyShowers,
+    /// Snow fall or snow grains.
+    Snow,
+    /// Snow showers, or flurries.
+    SnowShowers,
+    /// Sleet or ice pellets.
+    Sleet,
+    /// Hail.
+    Hail,
+    /// Thunderstorm.
+    Thunderstorm,
+    /// Thunderstorm with hail.
+    ThunderstormWithHail,
+    /// Strong wind or squalls.
+    Windy,
+    /// Tornado.
+    Tornado,
+    /// Hurricane, typhoon or tropical storm.
+    Hurricane,
+    /// Extreme heat.
+    Hot,
+    /// Extreme cold.
+    Cold,
+}
+
+/// Conditions of provider strings, normalized by [`condition_key`] and sorted for
+/// [`WeatherCondition::from_name`].
+const NAMES: &[(&str, WeatherCondition)] = &[
+    ("blizzard", WeatherCondition::Snow),
+    ("blowingsnow", WeatherCondition::Snow),
+    ("breezy", WeatherCondition::Windy),
+    ("chanceflurries", WeatherCondition::SnowShowers),
+    ("chancerain", WeatherCondition::Showers),
+    ("chancesleet", WeatherCondition::Sleet),
+    ("chancesnow", WeatherCondition::Snow),
+    ("chancetstorms", WeatherCondition::Thunderstorm),
+    ("clear", WeatherCondition::Clear),
+    ("clouds", WeatherCondition::Overcast),
+    ("cloudy", WeatherCondition::Overcast),
+    ("cold", WeatherCondition::Cold),
+    ("drizzle", WeatherCondition::Drizzle),
+    ("dust", WeatherCondition::Dust),
+    ("fair", WeatherCondition::MainlyClear),
+    ("flurries", WeatherCondition::SnowShowers),
+    ("fog", WeatherCondition::Fog),
+    ("foggy", WeatherCondition::Fog),
+    ("freezingdrizzle", WeatherCondition::FreezingDrizzle),
+    ("freezingfog", WeatherCondition::Fog),
+    ("freezingrain", WeatherCondition::FreezingRain),
+    ("hail", WeatherCondition::Hail),
+    ("haze", WeatherCondition::Haze),
+    ("heavyrain", WeatherCondition::HeavyRain),
+    ("heavyrainshowers", WeatherCondition::HeavyShowers),
+    ("heavyshowers", WeatherCondition::HeavyShowers),
+    ("heavysnow", WeatherCondition::Snow),
+    ("hot", WeatherCondition::Hot),
+    ("hurricane", WeatherCondition::Hurricane),
+    ("icepellets", WeatherCondition::Sleet),
+    ("lightdrizzle", WeatherCondition::Drizzle),
+    ("lightrain", WeatherCondition::Rain),
+    ("lightrainshowers", WeatherCondition::Showers),
+    ("lightsnow", WeatherCondition::Snow),
+    ("mist", WeatherCondition::Mist),
+    ("mostlyclear", WeatherCondition::MainlyClear),
+    ("mostlycloudy", WeatherCondition::Overcast),
+    ("mostlysunny", WeatherCondition::MainlyClear),
+    ("overcast", WeatherCondition::Overcast),
+    ("partlycloudy", WeatherCondition::PartlyCloudy),
+    ("partlysunny", WeatherCondition::PartlyCloudy),
+    ("rain", WeatherCondition::Rain),
+    ("rainshowers", WeatherCondition::Showers),
+    ("sand", WeatherCondition::Dust),
+    ("showers", WeatherCondition::Showers),
+    ("sleet", WeatherCondition::Sleet),
+    ("smoke", WeatherCondition::Haze),
+    ("snow", WeatherCondition::Snow),
+    ("snowshowers", WeatherCondition::SnowShowers),
+    ("squalls", WeatherCondition::Windy),
+    ("sunny", WeatherCondition::Clear),
+    ("thunderstorm", WeatherCondition::Thunderstorm),
+    ("thunderstorms", WeatherCondition::Thunderstorm),
+    ("tornado", WeatherCondition::Tornado),
+    ("tropicalstorm", WeatherCondition::Hurricane),
+    ("tstorms", WeatherCondition::Thunderstorm),
+    ("typhoon", WeatherCondition::Hurricane),
+    ("wind", WeatherCondition::Windy),
+    ("windy", WeatherCondition::Windy),
+];
+
+const _: () = assert!(
+    is_strictly_sorted(NAMES),
+    "NAMES must be sorted and free of duplicates"
+);
+
+impl WeatherCondition {
+    /// Returns the condition of a [WMO weather code] as used by e.g. Open-Meteo, or `None` for
+    /// codes outside that set.
+    ///
+    /// [WMO weather code]: https://open-meteo.com/en/docs#weather_variable_documentation
+    pub fn from_wmo_code(code: u8) -> Option<Self> {
+        Some(match code {
+            0 => Self::Clear,
+            1 => Self::MainlyClear,
+            2 => Self::PartlyCloudy,
+            3 => Self::Overcast,
+            45 | 48 => Self::Fog,
+            51 | 53 | 55 => Self::Drizzle,
+            56 | 57 => Self::FreezingDrizzle,
+            61 | 63 => Self::Rain,
+            65 => Self::HeavyRain,
+            66 | 67 => Self::FreezingRain,
+            71 | 73 | 75 | 77 => Self::Snow,
+            80 | 81 => Self::Showers,
+            82 => Self::HeavyShowers,
+            85 | 86 => Self::SnowShowers,
+            95 => Self::Thunderstorm,
+            96 | 99 => Self::ThunderstormWithHail,
+            _ => return None,
+        })
+    }
+
+    /// Returns the condition of a provider's condition string, ignoring case and punctuation,
+    /// e.g. `"Partly cloudy"`, `"partly-cloudy-night"`, `"Thunderstorm"` or `"mostly_sunny"`.
+    ///
+    /// A trailing `day` or `night` is ignored; pass it to [`for_condition`] instead.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let key = condition_key(name);
+        lookup(&key).or_else(|| {
+            let key = key
+                .strip_suffix("night")
+                .or_else(|| key.strip_suffix("day"))?;
+            lookup(key)
+        })
+    }
+}
+
+/// Returns the icon of `condition`, showing the moon instead of the sun at night where the
+/// condition has a night variant.
+///
+/// ```
+/// use gpui_remixicon::weather::{self, WeatherCondition};
+///
+/// let condition = WeatherCondition::from_wmo_code(2).unwrap();
+/// assert_eq!(
+///     weather::for_condition(condition, true),
+///     weather::Icon::MoonCloudyLine
+/// );
+/// ```
+pub fn for_condition(condition: WeatherCondition, is_night: bool) -> Icon {
+    match condition {
+        WeatherCondition::Clear if is_night => Icon::MoonClearLine,
+        WeatherCondition::Clear => Icon::SunLine,
+        WeatherCondition::MainlyClear | WeatherCondition::PartlyCloudy if is_night => {
+            Icon::MoonCloudyLine
+        }
+        WeatherCondition::MainlyClear | WeatherCondition::PartlyCloudy => Icon::SunCloudyLine,
+        WeatherCondition::Overcast => Icon::CloudyLine,
+        WeatherCondition::Fog => Icon::FoggyLine,
+        WeatherCondition::Mist => Icon::MistLine,
+        WeatherCondition::Haze if is_night => Icon::MoonFoggyLine,
+        WeatherCondition::Haze => Icon::SunFoggyLine,
+        WeatherCondition::Dust => Icon::Haze2Line,
+        WeatherCondition::Drizzle | WeatherCondition::FreezingDrizzle => Icon::DrizzleLine,
+        WeatherCondition::Rain | WeatherCondition::FreezingRain => Icon::RainyLine,
+        WeatherCondition::HeavyRain | WeatherCondition::HeavyShowers => Icon::HeavyShowersLine,
+        WeatherCondition::Showers => Icon::ShowersLine,
+        WeatherCondition::Snow | WeatherCondition::SnowShowers | WeatherCondition::Sleet => {
+            Icon::SnowyLine
+        }
+        WeatherCondition::Hail => Icon::HailLine,
+        WeatherCondition::Thunderstorm | WeatherCondition::ThunderstormWithHail => {
+            Icon::ThunderstormsLine
+        }
+        WeatherCondition::Windy => Icon::WindyLine,
+        WeatherCondition::Tornado => Icon::TornadoLine,
+        WeatherCondition::Hurricane => Icon::TyphoonLine,
+        WeatherCondition::Hot => Icon::TempHotLine,
+        WeatherCondition::Cold => Icon::TempColdLine,
+    }
+}
+
+/// Normalizes a condition string: ASCII letters only, lowercase.
+fn condition_key(name: &str) -> String {
+    name.chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+fn lookup(key: &str) -> Option<WeatherCondition> {
+    NAMES
+        .binary_search_by_key(&key, |(name, _)| name)
+        .ok()
+        .map(|index| NAMES[index].1)
+}
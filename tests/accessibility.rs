@@ -0,0 +1,49 @@
+//! Accessible names of icons.
+
+#![cfg(feature = "arrows")]
+
+use gpui_remixicon::{Icon, RemixIconName, arrows};
+
+fn label(icon: &Icon) -> Option<String> {
+    icon.accessible_label().map(|label| label.to_string())
+}
+
+#[test]
+fn default_labels_come_from_remixicon_labels() {
+    assert_eq!(arrows::Icon::ArrowUpSLine.accessible_label(), "arrow up s");
+    assert_eq!(arrows::Icon::ArrowUpSFill.accessible_label(), "arrow up s");
+    assert_eq!(
+        RemixIconName::ArrowsArrowUpSLine.accessible_label(),
+        "arrow up s"
+    );
+    assert_eq!(
+        label(&Icon::new(arrows::Icon::ArrowUpSLine)).as_deref(),
+        Some("arrow up s")
+    );
+    assert_eq!(
+        label(&Icon::named(arrows::IconBase::ArrowUpS)).as_deref(),
+        Some("arrow up s")
+    );
+}
+
+#[test]
+fn aria_label_and_decorative() {
+    let icon = Icon::new(arrows::Icon::ArrowUpSLine);
+    assert_eq!(
+        label(&icon.clone().aria_label("Scroll up")).as_deref(),
+        Some("Scroll up")
+    );
+
+    let decorative = icon.decorative();
+    assert!(decorative.is_decorative());
+    assert_eq!(label(&decorative), None);
+    assert_eq!(
+        label(&decorative.aria_label("Scroll up")).as_deref(),
+        Some("Scroll up")
+    );
+}
+
+#[test]
+fn custom_paths_have_no_default_label() {
+    assert_eq!(label(&Icon::empty().path("icons/custom.svg")), None);
+}
@@ -0,0 +1,117 @@
+//! Every generated icon variant must resolve to an SVG embedded in both the combined [`Assets`]
+//! and its category's `*Assets`.
+//!
+//! One test per category is generated by `build.rs`, so running the suite with any combination
+//! of category features checks exactly the enabled categories:
+//!
+//! ```bash
+//! cargo test --no-default-features --features arrows,system
+//! ```
+
+#![allow(dead_code)]
+
+use gpui::AssetSource;
+use gpui_remixicon::{Assets, RemixIcon, RemixIconName};
+use std::fmt::Debug;
+
+/// Assert that `icon` lives under `dir` and loads from both asset sources.
+fn assert_resolves<T>(icon: T, label: &str, category_assets: &impl AssetSource, dir: &str)
+where
+    T: RemixIcon + Into<RemixIconName> + TryFrom<RemixIconName, Error = RemixIconName>,
+    T: Copy + Debug + PartialEq,
+{
+    let path = icon.path();
+    assert_eq!(path, format!("{dir}{label}.svg"), "{icon:?}");
+
+    for (source, data) in [
+        ("Assets", Assets.load(&path)),
+        ("category assets", category_assets.load(&path)),
+    ] {
+        let data = data
+            .unwrap_or_else(|err| panic!("{icon:?}: {path} missing from {source}: {err}"))
+            .unwrap_or_else(|| panic!("{icon:?}: {path} missing from {source}"));
+        assert!(!data.is_empty(), "{icon:?}: {path} is empty in {source}");
+    }
+
+    let name = RemixIconName::from_label(label)
+        .unwrap_or_else(|| panic!("{icon:?}: no RemixIconName for `{label}`"));
+    assert_eq!(name.path(), path);
+    assert_eq!(name.label(), label);
+    assert_eq!(Into::<RemixIconName>::into(icon), name, "{icon:?}");
+    assert_eq!(T::try_from(name), Ok(icon));
+}
+
+/// Assert that a category's asset source embeds exactly its own `count` icons.
+fn assert_lists_only(category_assets: &impl AssetSource, dir: &str, count: usize) {
+    let paths = category_assets.list("").unwrap();
+    assert!(
+        paths.iter().all(|path| path.starts_with(dir)),
+        "category assets embed files outside {dir}"
+    );
+    assert_eq!(paths.len(), count, "category assets under {dir}");
+}
+
+#[cfg(all(feature = "arrows", feature = "system"))]
+#[test]
+fn conversion_rejects_other_categories() {
+    use gpui_remixicon::{Category, arrows, system};
+
+    let name = RemixIconName::SystemAddLine;
+    assert_eq!(name.category(), Category::System);
+    assert_eq!(arrows::Icon::try_from(name), Err(name));
+    assert_eq!(system::Icon::try_from(name), Ok(system::Icon::AddLine));
+}
+
+#[cfg(all(feature = "editor", feature = "system"))]
+#[test]
+fn base_names_pair_line_and_fill() {
+    use gpui_remixicon::{IconStyle, editor, system};
+
+    let star = system::IconBase::Star;
+    assert_eq!(star.resolve(IconStyle::Line), system::Icon::StarLine);
+    assert_eq!(star.resolve(IconStyle::Fill), system::Icon::StarFill);
+    assert_eq!(system::Icon::StarFill.base(), star);
+
+    // Editor icons have no filled variants and pass through unchanged
+    let bold = editor::IconBase::Bold;
+    assert_eq!(bold.resolve(IconStyle::Fill), editor::Icon::Bold);
+    assert_eq!(editor::Icon::Bold.base(), bold);
+}
+
+#[cfg(feature = "arrows")]
+#[gpui::test]
+fn named_icons_follow_style_preference(cx: &mut gpui::TestAppContext) {
+    use gpui::ParentElement as _;
+    use gpui_remixicon::test_support::{draw_icon, draw_icons, open_window};
+    use gpui_remixicon::{Icon, IconDefaults, IconStyle, arrows, icon_style_scope};
+
+    let named = || Icon::named(arrows::IconBase::ArrowUp);
+    let (line, fill) = (
+        arrows::Icon::ArrowUpLine.asset_path(),
+        arrows::Icon::ArrowUpFill.asset_path(),
+    );
+
+    assert_eq!(draw_icon(cx, named()).path, line);
+    cx.update(|cx| {
+        cx.set_global(IconDefaults {
+            style: IconStyle::Fill,
+            ..IconDefaults::default()
+        })
+    });
+    assert_eq!(draw_icon(cx, named()).path, fill);
+
+    // Scopes override the default, the innermost one winning
+    let cx = open_window(cx, move |_, _| {
+        gpui::div().child(named()).child(icon_style_scope(
+            "outer",
+            IconStyle::Line,
+            gpui::div()
+                .child(named())
+                .child(icon_style_scope("inner", IconStyle::Fill, named())),
+        ))
+    });
+    let paths: Vec<_> = draw_icons(cx).into_iter().map(|icon| icon.path).collect();
+    assert_eq!(paths, [fill, line, fill]);
+}
+
+include!(concat!(env!("OUT_DIR"), "/asset_tests.rs"));
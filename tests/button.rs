@@ -0,0 +1,116 @@
+//! Clicks and keyboard activation of icon buttons and toggles.
+
+#![cfg(feature = "system")]
+
+use gpui::{KeyUpEvent, Keystroke, Modifiers, TestAppContext, VisualTestContext};
+use gpui_remixicon::test_support::{draw_icon, draw_icons, open_window};
+use gpui_remixicon::{Icon, IconButton, IconRole, IconTheme, IconToggle, system};
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Click the center of the first icon in the window.
+fn click(cx: &mut VisualTestContext) {
+    let bounds = draw_icons(cx)[0].bounds;
+    cx.simulate_click(bounds.center(), Modifiers::none());
+}
+
+/// Release `key` over the focused element, which activates buttons.
+fn press(cx: &mut VisualTestContext, key: &str) {
+    cx.simulate_keystrokes(key);
+    cx.simulate_event(KeyUpEvent {
+        keystroke: Keystroke::parse(key).unwrap(),
+    });
+}
+
+/// Move focus to the next tab stop, as Tab does in apps binding it to `focus_next`.
+fn tab(cx: &mut VisualTestContext) {
+    cx.update(|window, _| window.focus_next());
+    cx.run_until_parked();
+}
+
+fn button(clicks: &Rc<Cell<usize>>, disabled: bool) -> IconButton {
+    let clicks = clicks.clone();
+    IconButton::new("delete", system::Icon::DeleteBinLine)
+        .disabled(disabled)
+        .on_click(move |_, _, _| clicks.set(clicks.get() + 1))
+}
+
+#[gpui::test]
+fn buttons_activate_on_click_and_keyboard(cx: &mut TestAppContext) {
+    let clicks = Rc::new(Cell::new(0));
+    let cx = open_window(cx, {
+        let clicks = clicks.clone();
+        move |_, _| button(&clicks, false)
+    });
+
+    click(cx);
+    assert_eq!(clicks.get(), 1);
+
+    tab(cx);
+    assert!(cx.update(|window, cx| window.focused(cx).is_some()));
+    press(cx, "enter");
+    press(cx, "space");
+    assert_eq!(clicks.get(), 3);
+}
+
+#[gpui::test]
+fn disabled_buttons_ignore_input(cx: &mut TestAppContext) {
+    let clicks = Rc::new(Cell::new(0));
+    let cx = open_window(cx, {
+        let clicks = clicks.clone();
+        move |_, _| button(&clicks, true)
+    });
+
+    click(cx);
+    tab(cx);
+    assert!(cx.update(|window, cx| window.focused(cx).is_none()));
+    press(cx, "enter");
+    assert_eq!(clicks.get(), 0);
+}
+
+#[gpui::test]
+fn toggles_report_the_new_state(cx: &mut TestAppContext) {
+    let selected = Rc::new(Cell::new(false));
+    let toggles = Rc::new(Cell::new(0));
+    let cx = open_window(cx, {
+        let (selected, toggles) = (selected.clone(), toggles.clone());
+        move |_, _| {
+            let (state, toggles) = (selected.clone(), toggles.clone());
+            IconToggle::new("star", system::IconBase::Star)
+                .selected(selected.get())
+                .on_toggle(move |selected, _, _| {
+                    state.set(*selected);
+                    toggles.set(toggles.get() + 1);
+                })
+        }
+    });
+    let accent = IconTheme::default().color(IconRole::Accent);
+
+    click(cx);
+    assert!(selected.get(), "on_toggle receives !selected");
+    let icon = draw_icons(cx).remove(0);
+    assert!(icon.path.ends_with("/star-fill.svg"), "{}", icon.path);
+    assert_eq!(icon.color, accent);
+
+    tab(cx);
+    press(cx, "space");
+    assert!(!selected.get());
+    assert_eq!(toggles.get(), 2);
+    let icon = draw_icons(cx).remove(0);
+    assert!(icon.path.ends_with("/star-line.svg"), "{}", icon.path);
+    assert_ne!(icon.color, accent);
+}
+
+#[gpui::test]
+fn toggles_fill_when_selected(cx: &mut TestAppContext) {
+    let toggle = IconToggle::new("star", system::IconBase::Star);
+
+    assert_eq!(
+        draw_icon(cx, toggle.icon()),
+        draw_icon(cx, Icon::new(system::Icon::StarLine))
+    );
+    assert_eq!(
+        draw_icon(cx, toggle.selected(true).icon()),
+        draw_icon(cx, Icon::new(system::Icon::StarFill).role(IconRole::Accent))
+    );
+}
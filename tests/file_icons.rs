@@ -0,0 +1,95 @@
+//! File-type icon resolution.
+
+#![cfg(feature = "document")]
+
+use gpui_remixicon::{FileIcons, RemixIconName, document, file_icon, mime_icon, mime_type};
+use std::path::Path;
+
+fn icon(path: &str) -> RemixIconName {
+    file_icon(Path::new(path), false, false)
+}
+
+#[test]
+fn directories() {
+    let dir = Path::new("src");
+    assert_eq!(
+        file_icon(dir, true, false),
+        document::Icon::FolderLine.into()
+    );
+    assert_eq!(
+        file_icon(dir, true, true),
+        document::Icon::FolderOpenLine.into()
+    );
+}
+
+#[test]
+fn built_in_tables() {
+    for (path, expected) in [
+        ("src/main.rs", document::Icon::FileCodeLine),
+        ("index.HTML", document::Icon::FileCodeLine),
+        ("photo.png", document::Icon::FileImageLine),
+        ("Photo.JPEG", document::Icon::FileImageLine),
+        ("loop.gif", document::Icon::FileGifLine),
+        ("song.mp3", document::Icon::FileMusicLine),
+        ("clip.mov", document::Icon::FileVideoLine),
+        ("release.tar.gz", document::Icon::FileZipLine),
+        ("report.pdf", document::Icon::FilePdf2Line),
+        ("letter.docx", document::Icon::FileWord2Line),
+        ("budget.xlsx", document::Icon::FileExcel2Line),
+        ("data.csv", document::Icon::FileExcel2Line),
+        ("slides.pptx", document::Icon::FilePpt2Line),
+        ("notes.md", document::Icon::MarkdownLine),
+        ("notes.txt", document::Icon::FileTextLine),
+        ("Cargo.toml", document::Icon::FileSettingsLine),
+        ("Cargo.lock", document::Icon::FileLockLine),
+        ("README.md", document::Icon::FileInfoLine),
+        ("LICENSE", document::Icon::FilePaper2Line),
+        ("Dockerfile", document::Icon::FileCodeLine),
+        (".gitignore", document::Icon::FileSettingsLine),
+        ("unknown.xyz", document::Icon::FileLine),
+        ("no-extension", document::Icon::FileLine),
+    ] {
+        assert_eq!(icon(path), expected.into(), "{path}");
+    }
+}
+
+#[test]
+fn mime_types() {
+    assert_eq!(mime_type(Path::new("photo.JPG")), Some("image/jpeg"));
+    assert_eq!(mime_type(Path::new("main.rs")), None);
+    assert_eq!(
+        mime_icon("text/plain; charset=utf-8"),
+        document::Icon::FileTextLine.into()
+    );
+    assert_eq!(
+        mime_icon("IMAGE/x-custom"),
+        document::Icon::FileImageLine.into()
+    );
+    assert_eq!(
+        mime_icon("application/octet-stream"),
+        document::Icon::FileLine.into()
+    );
+    assert_eq!(mime_icon(""), document::Icon::FileLine.into());
+}
+
+#[test]
+fn overrides_take_precedence() {
+    let mut icons = FileIcons::default();
+    icons.register_extension(".RS", document::Icon::File2Line);
+    icons.register_file_name("readme.md", document::Icon::FileList2Line);
+    icons.register_mime_type("image/png", document::Icon::File3Line);
+
+    let resolve = |path: &str| icons.icon(Path::new(path), false, false);
+    assert_eq!(resolve("lib.rs"), document::Icon::File2Line.into());
+    assert_eq!(resolve("README.md"), document::Icon::FileList2Line.into());
+    assert_eq!(resolve("notes.md"), document::Icon::MarkdownLine.into());
+    assert_eq!(resolve("photo.png"), document::Icon::File3Line.into());
+    assert_eq!(
+        icons.mime_icon("image/png; q=1"),
+        document::Icon::File3Line.into()
+    );
+    assert_eq!(
+        icons.icon(Path::new("lib.rs"), true, false),
+        document::Icon::FolderLine.into()
+    );
+}
@@ -0,0 +1,66 @@
+//! Layout of icons next to their labels.
+
+#![cfg(feature = "system")]
+
+use gpui::{Bounds, ParentElement as _, Pixels, Styled as _, TestAppContext, div, px, rems};
+use gpui_remixicon::test_support::{draw_icons, open_window};
+use gpui_remixicon::{IconGap, IconLabel, system};
+
+const LABEL: &str = "A settings label long enough to overflow a narrow row of 200 pixels";
+
+/// The bounds of the icon of the label returned by `render`, drawn alone in a new window.
+fn icon_bounds(
+    cx: &mut TestAppContext,
+    render: impl Fn() -> IconLabel + 'static,
+) -> Bounds<Pixels> {
+    let cx = open_window(cx, move |_, _| div().w(px(200.)).child(render()));
+    draw_icons(cx)[0].bounds
+}
+
+#[gpui::test]
+fn icons_are_centered_on_the_label_line(cx: &mut TestAppContext) {
+    let bounds = icon_bounds(cx, || {
+        IconLabel::new(system::Icon::SettingsLine, "Settings").text_xl()
+    });
+    let line_height = open_window(cx, |_, _| div()).update(|window, _| {
+        let mut style = window.text_style();
+        style.font_size = rems(1.25).into();
+        style.line_height_in_pixels(window.rem_size())
+    });
+
+    // The icon follows the label's text size, not the window's
+    assert_eq!(bounds.size.height, px(20.));
+    assert_eq!(bounds.origin.y, (line_height - px(20.)) / 2.);
+}
+
+#[gpui::test]
+fn gap_and_position(cx: &mut TestAppContext) {
+    let label = |gap| move || IconLabel::new(system::Icon::SettingsLine, "Settings").gap(gap);
+
+    assert_eq!(icon_bounds(cx, label(IconGap::Large)).origin.x, px(0.));
+
+    let trailing = |gap| {
+        let label = label(gap);
+        move || label().trailing()
+    };
+    let small = icon_bounds(cx, trailing(IconGap::Small)).origin.x;
+    let large = icon_bounds(cx, trailing(IconGap::Large)).origin.x;
+    let custom = icon_bounds(cx, trailing(IconGap::Custom(px(20.).into())))
+        .origin
+        .x;
+    assert!(small > px(4.), "trailing icon follows the text");
+    assert_eq!(large - small, px(4.));
+    assert_eq!(custom - small, px(16.));
+}
+
+#[gpui::test]
+fn truncated_labels_keep_their_icon_visible(cx: &mut TestAppContext) {
+    let label = || IconLabel::new(system::Icon::SettingsLine, LABEL).trailing();
+
+    // Without truncating, the text pushes the icon out of the row
+    let overflowing = icon_bounds(cx, label);
+    let truncated = icon_bounds(cx, move || label().truncate());
+    assert!(overflowing.left() > px(200.));
+    assert!(truncated.right() <= px(200.));
+    assert_eq!(truncated.origin.y, overflowing.origin.y);
+}
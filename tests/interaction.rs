@@ -0,0 +1,96 @@
+//! Hover, pressed, group hover and disabled states of icons.
+
+#![cfg(feature = "arrows")]
+
+use gpui::{
+    InteractiveElement as _, Modifiers, MouseButton, ParentElement as _, Styled as _,
+    TestAppContext, div, point, px,
+};
+use gpui_remixicon::test_support::{draw_icons, open_window};
+use gpui_remixicon::{Icon, arrows};
+
+const ICON: arrows::Icon = arrows::Icon::ArrowUpLine;
+
+#[gpui::test]
+fn interaction_states(cx: &mut TestAppContext) {
+    fn icon() -> Icon {
+        Icon::new(ICON)
+            .large()
+            .id("icon")
+            .hover_color(gpui::red())
+            .active_color(gpui::blue())
+            .group_hover_color("row", gpui::green())
+    }
+    // The mouse starts at the window origin, outside the row
+    let row = |icon: fn() -> Icon| {
+        move |_: &mut _, _: &mut _| div().p_8().child(div().group("row").p_4().child(icon()))
+    };
+    let color = |cx: &mut _| draw_icons(cx)[0].color;
+
+    let cx = open_window(cx, row(icon));
+    let bounds = draw_icons(cx)[0].bounds;
+    assert_eq!(color(cx), gpui::black());
+
+    cx.simulate_mouse_move(
+        bounds.origin - point(px(8.), px(8.)),
+        None,
+        Modifiers::none(),
+    );
+    assert_eq!(color(cx), gpui::green(), "group hovered");
+    cx.simulate_mouse_move(bounds.center(), None, Modifiers::none());
+    assert_eq!(color(cx), gpui::red(), "hovered");
+    cx.simulate_mouse_down(bounds.center(), MouseButton::Left, Modifiers::none());
+    assert_eq!(color(cx), gpui::blue(), "pressed");
+    cx.simulate_mouse_up(bounds.center(), MouseButton::Left, Modifiers::none());
+    assert_eq!(color(cx), gpui::red(), "released");
+
+    // Presses are only tracked for icons with an id
+    let cx = open_window(
+        cx,
+        row(|| Icon::new(ICON).large().active_color(gpui::blue())),
+    );
+    cx.simulate_mouse_down(bounds.center(), MouseButton::Left, Modifiers::none());
+    assert_eq!(color(cx), gpui::black());
+
+    // Disabled icons are dimmed and ignore their state colors
+    let cx = open_window(cx, row(|| icon().disabled(true)));
+    let dimmed = gpui::black().opacity(0.5);
+    assert_eq!(color(cx), dimmed);
+    cx.simulate_mouse_move(
+        bounds.origin - point(px(8.), px(8.)),
+        None,
+        Modifiers::none(),
+    );
+    assert_eq!(color(cx), dimmed, "group hovered");
+    cx.simulate_mouse_move(bounds.center(), None, Modifiers::none());
+    assert_eq!(color(cx), dimmed, "hovered");
+    cx.simulate_mouse_down(bounds.center(), MouseButton::Left, Modifiers::none());
+    assert_eq!(color(cx), dimmed, "pressed");
+}
+
+#[gpui::test]
+fn interaction_states_end_with_the_mouse(cx: &mut TestAppContext) {
+    let cx = open_window(cx, |_, _| {
+        div().p_8().child(
+            div().group("row").p_4().child(
+                Icon::new(ICON)
+                    .large()
+                    .hover_color(gpui::red())
+                    .group_hover_color("row", gpui::green()),
+            ),
+        )
+    });
+    let color = |cx: &mut _| draw_icons(cx)[0].color;
+    let bounds = draw_icons(cx)[0].bounds;
+
+    cx.simulate_mouse_move(bounds.center(), None, Modifiers::none());
+    assert_eq!(color(cx), gpui::red());
+    cx.simulate_mouse_move(
+        bounds.origin - point(px(8.), px(8.)),
+        None,
+        Modifiers::none(),
+    );
+    assert_eq!(color(cx), gpui::green());
+    cx.simulate_mouse_move(point(px(0.), px(0.)), None, Modifiers::none());
+    assert_eq!(color(cx), gpui::black());
+}
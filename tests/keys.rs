@@ -0,0 +1,172 @@
+//! Keyboard shortcut glyphs.
+
+#![cfg(all(
+    feature = "arrows",
+    feature = "development",
+    feature = "editor",
+    feature = "system"
+))]
+
+use gpui::{Keystroke, Pixels, TestAppContext, VisualTestContext, px, size};
+use gpui_remixicon::test_support::{DrawnIcon, draw_icons, open_window};
+use gpui_remixicon::{
+    KeyBinding, KeyGlyph, KeyPlatform, Sizable as _, Size, arrows, development, editor, key_icon,
+    key_icon_for, system,
+};
+
+const PLATFORMS: [KeyPlatform; 3] = [KeyPlatform::MacOs, KeyPlatform::Windows, KeyPlatform::Linux];
+
+fn glyphs(source: &str, platform: KeyPlatform) -> Vec<KeyGlyph> {
+    key_icon_for(&Keystroke::parse(source).unwrap(), platform)
+}
+
+fn icon(icon: impl Into<gpui_remixicon::RemixIconName>, label: &'static str) -> KeyGlyph {
+    KeyGlyph::Icon {
+        icon: icon.into(),
+        label,
+    }
+}
+
+fn text(text: &'static str) -> KeyGlyph {
+    KeyGlyph::Text(text.into())
+}
+
+#[test]
+fn keys() {
+    for platform in PLATFORMS {
+        let mac = platform == KeyPlatform::MacOs;
+        for (key, expected) in [
+            (
+                "enter",
+                icon(
+                    arrows::Icon::CornerDownLeftLine,
+                    if mac { "Return" } else { "Enter" },
+                ),
+            ),
+            (
+                "backspace",
+                icon(
+                    system::Icon::DeleteBackLine,
+                    if mac { "Delete" } else { "Backspace" },
+                ),
+            ),
+            ("space", icon(editor::Icon::Space, "Space")),
+            ("up", icon(arrows::Icon::ArrowUpLine, "Up")),
+            ("down", icon(arrows::Icon::ArrowDownLine, "Down")),
+            ("left", icon(arrows::Icon::ArrowLeftLine, "Left")),
+            ("right", icon(arrows::Icon::ArrowRightLine, "Right")),
+            ("tab", text(if mac { "⇥" } else { "Tab" })),
+            ("escape", text(if mac { "⎋" } else { "Esc" })),
+            ("k", text("K")),
+            ("f12", text("F12")),
+            ("pageup", text("PgUp")),
+        ] {
+            assert_eq!(glyphs(key, platform), [expected], "{key} on {platform:?}");
+        }
+    }
+}
+
+#[test]
+fn modifiers() {
+    assert_eq!(
+        glyphs("fn-cmd-shift-ctrl-alt-p", KeyPlatform::MacOs),
+        [
+            text("fn"),
+            icon(arrows::Icon::ArrowUpSLine, "Control"),
+            text("⌥"),
+            text("⇧"),
+            icon(development::Icon::CommandLine, "Command"),
+            text("P"),
+        ]
+    );
+    assert_eq!(
+        glyphs("fn-cmd-shift-ctrl-alt-p", KeyPlatform::Windows),
+        ["Fn", "Ctrl", "Alt", "Shift", "Win", "P"].map(text)
+    );
+    assert_eq!(
+        glyphs("fn-cmd-shift-ctrl-alt-p", KeyPlatform::Linux),
+        ["Fn", "Ctrl", "Alt", "Shift", "Super", "P"].map(text)
+    );
+}
+
+#[test]
+fn current_platform() {
+    let keystroke = Keystroke::parse("cmd-backspace").unwrap();
+    assert_eq!(
+        key_icon(&keystroke),
+        key_icon_for(&keystroke, KeyPlatform::current())
+    );
+    assert_eq!(KeyPlatform::default(), KeyPlatform::current());
+    #[cfg(target_os = "linux")]
+    assert_eq!(KeyPlatform::current(), KeyPlatform::Linux);
+}
+
+#[test]
+fn parse_sequences() {
+    let binding = KeyBinding::parse("ctrl-k  ctrl-s").unwrap();
+    assert_eq!(binding.keystrokes().len(), 2);
+    assert_eq!(binding.keystrokes()[1].key, "s");
+    assert!(KeyBinding::parse("ctrl-k ctrl-k-s").is_err());
+}
+
+/// The icons of `source` drawn as a large binding on `platform`.
+fn draw(cx: &mut TestAppContext, source: &'static str, platform: KeyPlatform) -> Vec<DrawnIcon> {
+    let cx = open_window(cx, move |_, _| {
+        KeyBinding::parse(source)
+            .unwrap()
+            .platform(platform)
+            .with_size(Size::Large)
+    });
+    draw_icons(cx)
+}
+
+/// The width of `text` in the window's text style, rounded up like text elements do.
+fn text_width(cx: &mut VisualTestContext, text: &'static str) -> Pixels {
+    cx.update(|window, _| {
+        let style = window.text_style();
+        let font_size = style.font_size.to_pixels(window.rem_size());
+        window
+            .text_system()
+            .shape_line(text.into(), font_size, &[style.to_run(text.len())], None)
+            .width
+            .ceil()
+    })
+}
+
+#[gpui::test]
+fn bindings_draw_sized_glyphs(cx: &mut TestAppContext) {
+    for platform in PLATFORMS {
+        let icons = draw(cx, "up ctrl-down", platform);
+        let expected = if platform == KeyPlatform::MacOs { 3 } else { 2 };
+        assert_eq!(icons.len(), expected, "{platform:?}");
+        for icon in icons {
+            assert_eq!(icon.bounds.size, size(px(24.), px(24.)), "{platform:?}");
+        }
+    }
+}
+
+#[gpui::test]
+fn mac_glyphs_sit_next_to_each_other(cx: &mut TestAppContext) {
+    let icons = draw(cx, "ctrl-up cmd-enter", KeyPlatform::MacOs);
+    assert_eq!(icons.len(), 4);
+    // 2px between the glyphs of a keystroke, 6px between keystrokes
+    assert_eq!(icons[1].bounds.left() - icons[0].bounds.right(), px(2.));
+    assert_eq!(icons[2].bounds.left() - icons[1].bounds.right(), px(6.));
+    assert_eq!(icons[3].bounds.left() - icons[2].bounds.right(), px(2.));
+}
+
+#[gpui::test]
+fn other_platforms_join_glyphs_with_plus(cx: &mut TestAppContext) {
+    let window = open_window(cx, |_, _| gpui::div());
+    let ctrl = text_width(window, "Ctrl");
+    let plus = text_width(window, "+");
+
+    for platform in [KeyPlatform::Windows, KeyPlatform::Linux] {
+        let icons = draw(cx, "ctrl-up", platform);
+        assert_eq!(
+            icons[0].bounds.left(),
+            ctrl + px(2.) + plus + px(2.),
+            "{platform:?}"
+        );
+    }
+}
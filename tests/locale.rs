@@ -0,0 +1,82 @@
+//! Locale switcher labels and compass directions.
+
+#[cfg(feature = "editor")]
+#[test]
+fn language_names() {
+    use gpui_remixicon::language_name;
+
+    assert_eq!(language_name("en"), Some("English"));
+    assert_eq!(language_name("de-AT"), Some("Deutsch"));
+    assert_eq!(language_name("PT_br"), Some("Português"));
+    assert_eq!(language_name("zh-Hant-TW"), Some("中文"));
+    assert_eq!(language_name("fil-PH"), Some("Filipino"));
+    assert_eq!(language_name("tlh"), None);
+    assert_eq!(language_name(""), None);
+}
+
+#[cfg(feature = "editor")]
+#[test]
+fn locale_names() {
+    use gpui_remixicon::locale_name;
+
+    for (tag, expected) in [
+        ("en", "English"),
+        ("en-US", "English (US)"),
+        ("pt_BR", "Português (BR)"),
+        ("es-419", "Español (419)"),
+        ("sr-Latn-RS", "Српски (RS)"),
+        ("zh-yue-HK", "中文 (HK)"),
+        ("zh-cmn-Hans-CN", "中文 (CN)"),
+        ("zh-Hant", "中文"),
+        ("sl-rozaj", "Slovenščina"),
+        ("tlh-US", "tlh-US"),
+    ] {
+        assert_eq!(locale_name(tag), expected, "{tag}");
+    }
+}
+
+#[cfg(feature = "editor")]
+#[gpui::test]
+fn locale_labels_draw_the_translate_icon(cx: &mut gpui::TestAppContext) {
+    use gpui_remixicon::test_support::{draw_icons, open_window};
+    use gpui_remixicon::{RemixIcon as _, editor, locale_label};
+
+    let cx = open_window(cx, |_, _| locale_label("de-AT"));
+    let icons = draw_icons(cx);
+    assert_eq!(icons.len(), 1);
+    assert_eq!(icons[0].path, editor::Icon::Translate2.path());
+}
+
+#[cfg(feature = "arrows")]
+#[test]
+fn compass_directions() {
+    use gpui_remixicon::arrows::{CompassDirection, Icon};
+
+    assert_eq!(CompassDirection::North.icon(), Icon::ArrowUpLine);
+    assert_eq!(
+        Icon::from(CompassDirection::SouthWest),
+        Icon::ArrowLeftDownLine
+    );
+    for (degrees, expected) in [
+        (0., CompassDirection::North),
+        (22., CompassDirection::North),
+        (23., CompassDirection::NorthEast),
+        (90., CompassDirection::East),
+        (200., CompassDirection::South),
+        (350., CompassDirection::North),
+        (-45., CompassDirection::NorthWest),
+        (405., CompassDirection::NorthEast),
+    ] {
+        assert_eq!(
+            CompassDirection::from_degrees(degrees),
+            expected,
+            "{degrees}"
+        );
+    }
+    for direction in CompassDirection::ALL {
+        assert_eq!(
+            CompassDirection::from_degrees(direction.degrees()),
+            direction
+        );
+    }
+}
@@ -0,0 +1,45 @@
+//! Brand logo lookups by service name and URL.
+
+#![cfg(feature = "logos")]
+
+use gpui_remixicon::logos::{Icon, for_service, from_url};
+
+#[test]
+fn services_by_name_and_alias() {
+    assert_eq!(for_service("github"), Some(Icon::GithubFill));
+    assert_eq!(for_service("GitHub"), Some(Icon::GithubFill));
+    assert_eq!(for_service("gh"), Some(Icon::GithubFill));
+    assert_eq!(for_service("YouTube"), Some(Icon::YoutubeFill));
+    assert_eq!(for_service("twitter-x"), Some(Icon::TwitterXFill));
+    assert_eq!(for_service("X"), Some(Icon::TwitterXFill));
+    assert_eq!(for_service("Twitter"), Some(Icon::TwitterFill));
+    assert!(for_service("fediverse").is_some());
+    assert_eq!(for_service("myspace"), None);
+    assert_eq!(for_service(""), None);
+}
+
+#[test]
+fn services_by_url() {
+    assert_eq!(
+        from_url("https://gitlab.com/group/project"),
+        Some(Icon::GitlabFill)
+    );
+    assert_eq!(
+        from_url("https://gist.github.com/user/1234"),
+        Some(Icon::GithubFill)
+    );
+    assert_eq!(
+        from_url("http://user@WWW.YouTube.com:443/watch?v=1"),
+        Some(Icon::YoutubeFill)
+    );
+    assert_eq!(from_url("youtu.be/abc#t=10"), Some(Icon::YoutubeFill));
+    assert_eq!(from_url("https://x.com/home"), Some(Icon::TwitterXFill));
+    assert_eq!(
+        from_url("https://twitter.com./home"),
+        Some(Icon::TwitterFill)
+    );
+    assert_eq!(from_url("https://notgithub.com"), None);
+    assert_eq!(from_url("https://example.com/github.com"), None);
+    assert_eq!(from_url(""), None);
+}
+
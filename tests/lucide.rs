@@ -0,0 +1,54 @@
+//! The Lucide migration table covers every gpui-component icon with an embedded RemixIcon.
+
+#![cfg(all(
+    feature = "gpui-component",
+    feature = "arrows",
+    feature = "buildings",
+    feature = "business",
+    feature = "design",
+    feature = "development",
+    feature = "document",
+    feature = "editor",
+    feature = "health_and_medical",
+    feature = "logos",
+    feature = "map",
+    feature = "media",
+    feature = "system",
+    feature = "user_and_faces",
+    feature = "weather",
+))]
+
+use gpui::AssetSource;
+use gpui_component::IconNamed as _;
+use gpui_remixicon::lucide::{LucideAssets, lucide_icon_for_path, lucide_paths, remix_equivalent};
+use gpui_remixicon::{Assets, RemixIcon};
+
+#[test]
+fn every_icon_name_resolves() {
+    let paths: Vec<_> = lucide_paths().collect();
+    for pair in paths.windows(2) {
+        assert_ne!(pair[0], pair[1], "duplicate ICON_NAMES entry");
+    }
+    for path in paths {
+        let name = lucide_icon_for_path(path).unwrap_or_else(|| panic!("`{path}` not found"));
+        assert_eq!(&name.clone().path(), path);
+
+        let remix = remix_equivalent(name);
+        assert!(
+            Assets.load(&remix.path()).unwrap().is_some(),
+            "`{path}` maps to missing asset {}",
+            remix.path()
+        );
+        assert!(LucideAssets(Assets).load(path).unwrap().is_some(), "{path}");
+    }
+}
+
+#[test]
+fn other_paths_are_delegated() {
+    assert!(lucide_icon_for_path("icons/not-a-lucide-icon.svg").is_none());
+    let path = "icons/System/add-line.svg";
+    assert_eq!(
+        LucideAssets(Assets).load(path).unwrap(),
+        Assets.load(path).unwrap()
+    );
+}
@@ -0,0 +1,86 @@
+//! Serialization of icons as RemixIcon labels.
+
+#![cfg(all(feature = "serde", feature = "arrows", feature = "system"))]
+
+use gpui_remixicon::{RemixIconName, arrows, system};
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn round_trip() {
+    let json = serde_json::to_string(&arrows::Icon::ArrowUpSLine).unwrap();
+    assert_eq!(json, r#""arrow-up-s-line""#);
+    let icon: arrows::Icon = serde_json::from_str(&json).unwrap();
+    assert_eq!(icon, arrows::Icon::ArrowUpSLine);
+
+    let name = RemixIconName::from(system::Icon::AddLine);
+    let json = serde_json::to_string(&name).unwrap();
+    assert_eq!(json, r#""add-line""#);
+    assert_eq!(serde_json::from_str::<RemixIconName>(&json).unwrap(), name);
+}
+
+#[test]
+fn unknown_label() {
+    let err = serde_json::from_str::<RemixIconName>(r#""no-such-icon""#).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("unknown RemixIcon label `no-such-icon`"),
+        "{err}"
+    );
+}
+
+#[test]
+fn label_of_another_category() {
+    let err = serde_json::from_str::<arrows::Icon>(r#""add-line""#).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("belongs to the `system` category, expected `arrows`"),
+        "{err}"
+    );
+}
+
+#[cfg(not(feature = "weather"))]
+#[test]
+fn label_of_disabled_category() {
+    let err = serde_json::from_str::<RemixIconName>(r#""sun-line""#).unwrap_err();
+    assert!(
+        err.to_string().contains("requires the `weather` feature"),
+        "{err}"
+    );
+}
+
+#[test]
+fn qualified_labels_are_opt_in() {
+    let err = serde_json::from_str::<arrows::Icon>(r#""arrows/arrow-up-s-line""#).unwrap_err();
+    assert!(
+        err.to_string().contains("gpui_remixicon::label::qualified"),
+        "{err}"
+    );
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Qualified {
+    #[serde(with = "gpui_remixicon::label::qualified")]
+    icon: RemixIconName,
+}
+
+#[test]
+fn qualified_round_trip() {
+    let value = Qualified {
+        icon: arrows::Icon::ArrowUpSLine.into(),
+    };
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"icon":"arrows/arrow-up-s-line"}"#);
+    assert_eq!(serde_json::from_str::<Qualified>(&json).unwrap(), value);
+
+    // Bare labels are accepted too
+    let bare: Qualified = serde_json::from_str(r#"{"icon":"arrow-up-s-line"}"#).unwrap();
+    assert_eq!(bare, value);
+
+    let err =
+        serde_json::from_str::<Qualified>(r#"{"icon":"system/arrow-up-s-line"}"#).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("belongs to the `arrows` category, not `system`"),
+        "{err}"
+    );
+}
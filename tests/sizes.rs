@@ -0,0 +1,68 @@
+//! Icon sizes resolved from the `IconSizes` and `IconDefaults` globals.
+
+#![cfg(feature = "arrows")]
+
+use gpui::{
+    ParentElement as _, Styled as _, TestAppContext, VisualTestContext, div, px, rems, size,
+};
+use gpui_remixicon::test_support::{draw_icon, draw_icons, open_window};
+use gpui_remixicon::{Icon, IconDefaults, IconSizes, Size, arrows};
+
+const ICON: arrows::Icon = arrows::Icon::ArrowUpLine;
+
+#[gpui::test]
+fn size_globals(cx: &mut TestAppContext) {
+    cx.update(|cx| {
+        cx.set_global(IconSizes {
+            medium: px(18.).into(),
+            large: px(20.).into(),
+            ..IconSizes::default()
+        });
+        cx.set_global(IconDefaults {
+            size: Some(Size::Medium),
+            ..IconDefaults::default()
+        });
+    });
+    let mut edge = |icon: Icon| draw_icon(cx, icon).bounds.size;
+
+    assert_eq!(edge(Icon::new(ICON).large()), size(px(20.), px(20.)));
+    assert_eq!(
+        edge(Icon::new(ICON)),
+        size(px(18.), px(18.)),
+        "default size"
+    );
+    assert_eq!(edge(Icon::new(ICON).xlarge()), size(px(32.), px(32.)));
+}
+
+#[gpui::test]
+fn size_resolution(cx: &mut TestAppContext) {
+    let edge = |cx: &mut VisualTestContext| draw_icons(cx)[0].bounds.size.width;
+
+    // Without a size, icons follow the text size
+    let cx = open_window(cx, |_, _| div().text_size(px(20.)).child(Icon::new(ICON)));
+    assert_eq!(edge(cx), px(20.));
+
+    // Default sizes apply to unsized icons only, and are read on every frame
+    cx.update(|_, cx| {
+        cx.set_global(IconDefaults {
+            size: Some(Size::Large),
+            ..IconDefaults::default()
+        })
+    });
+    assert_eq!(edge(cx), px(24.));
+    let cx = open_window(cx, |_, _| Icon::new(ICON).size_5());
+    assert_eq!(edge(cx), px(20.), "style size");
+
+    // Relative presets follow the window's rem size
+    cx.update(|_, cx| {
+        cx.set_global(IconSizes {
+            large: rems(2.).into(),
+            ..IconSizes::default()
+        })
+    });
+    let cx = open_window(cx, |window, _| {
+        window.set_rem_size(px(10.));
+        Icon::new(ICON).large()
+    });
+    assert_eq!(edge(cx), px(20.));
+}
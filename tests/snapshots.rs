@@ -0,0 +1,88 @@
+//! Golden-image snapshots of `Icon` configurations, drawn in GPUI test windows and rasterized on
+//! the CPU.
+//!
+//! Run with `UPDATE_SNAPSHOTS=1 cargo test --test snapshots` to bless new golden images.
+
+#![cfg(feature = "arrows")]
+
+use gpui::{Hsla, Styled as _, TestAppContext, px, radians};
+use gpui_remixicon::test_support::{assert_snapshot, draw_icon, rasterize};
+use gpui_remixicon::{Icon, arrows};
+use std::f32::consts::FRAC_PI_2;
+
+/// Asymmetric along both axes, so flips and rotations are all distinguishable.
+const ICON: arrows::Icon = arrows::Icon::CornerDownRightLine;
+
+fn snapshot(cx: &mut TestAppContext, name: &str, icon: Icon) {
+    let pixmap = rasterize(&draw_icon(cx, icon));
+    assert_snapshot(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/tests/snapshots"),
+        name,
+        &pixmap,
+    );
+}
+
+#[gpui::test]
+fn sizes(cx: &mut TestAppContext) {
+    snapshot(cx, "size_default", Icon::new(ICON));
+    snapshot(cx, "size_xsmall", Icon::new(ICON).xsmall());
+    snapshot(cx, "size_small", Icon::new(ICON).small());
+    snapshot(cx, "size_medium", Icon::new(ICON).medium());
+    snapshot(cx, "size_large", Icon::new(ICON).large());
+    snapshot(cx, "size_xlarge", Icon::new(ICON).xlarge());
+    snapshot(cx, "size_xxlarge", Icon::new(ICON).xxlarge());
+    snapshot(cx, "size_custom", Icon::new(ICON).custom_size(px(32.)));
+}
+
+#[gpui::test]
+fn rotation(cx: &mut TestAppContext) {
+    snapshot(
+        cx,
+        "rotate_90",
+        Icon::new(ICON).large().rotate(radians(FRAC_PI_2)),
+    );
+}
+
+#[gpui::test]
+fn color(cx: &mut TestAppContext) {
+    snapshot(
+        cx,
+        "color_red",
+        Icon::new(ICON).large().text_color(gpui::red()),
+    );
+    snapshot(
+        cx,
+        "color_translucent",
+        Icon::new(ICON).large().text_color(Hsla {
+            a: 0.5,
+            ..gpui::blue()
+        }),
+    );
+}
+
+#[gpui::test]
+fn flips(cx: &mut TestAppContext) {
+    snapshot(
+        cx,
+        "flip_horizontal",
+        Icon::new(ICON).large().flip_horizontal(),
+    );
+    snapshot(cx, "flip_vertical", Icon::new(ICON).large().flip_vertical());
+    snapshot(
+        cx,
+        "flip_both_rotate_90",
+        Icon::new(ICON)
+            .large()
+            .flip_horizontal()
+            .flip_vertical()
+            .rotate(radians(FRAC_PI_2)),
+    );
+    // Flipping is idempotent rather than a toggle
+    assert_eq!(
+        Icon::new(ICON)
+            .flip_horizontal()
+            .flip_horizontal()
+            .transformation(),
+        Icon::new(ICON).flip_horizontal().transformation()
+    );
+}
@@ -0,0 +1,116 @@
+//! Icons, colors and animations of status indicators.
+
+#![cfg(feature = "system")]
+
+use gpui::{Styled as _, TestAppContext, VisualTestContext, ease_out_quint};
+use gpui_remixicon::test_support::{draw_icon, draw_icons, open_window};
+use gpui_remixicon::{Icon, IconRole, Status, StatusIcon, StatusIcons, system};
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::Duration;
+
+#[gpui::test]
+fn default_icons_follow_the_theme(cx: &mut TestAppContext) {
+    let icons = StatusIcons::default();
+    let mut render = |icon: &Icon| {
+        let drawn = draw_icon(cx, icon.clone().large());
+        (drawn.path, drawn.color)
+    };
+
+    for (status, expected, role) in [
+        (
+            Status::Idle,
+            system::Icon::CheckboxBlankCircleLine,
+            IconRole::Muted,
+        ),
+        (Status::Loading, system::Icon::Loader4Line, IconRole::Accent),
+        (
+            Status::Success,
+            system::Icon::CheckboxCircleFill,
+            IconRole::Success,
+        ),
+        (Status::Warning, system::Icon::AlertFill, IconRole::Warning),
+        (
+            Status::Error,
+            system::Icon::ErrorWarningFill,
+            IconRole::Danger,
+        ),
+    ] {
+        assert_eq!(
+            render(icons.icon(status)),
+            render(&Icon::new(expected).role(role)),
+            "{status:?}"
+        );
+    }
+}
+
+#[test]
+fn default_icons_are_labeled_by_status() {
+    let icons = StatusIcons::default();
+    for status in Status::ALL {
+        assert_eq!(
+            icons
+                .icon(status)
+                .accessible_label()
+                .map(|label| label.to_string()),
+            Some(status.label().to_string())
+        );
+    }
+}
+
+#[gpui::test]
+fn installed_icons_replace_the_defaults(cx: &mut TestAppContext) {
+    cx.update(|cx| {
+        cx.set_global(StatusIcons {
+            error: Icon::new(system::Icon::CloseCircleFill).text_color(gpui::red()),
+            ..StatusIcons::default()
+        })
+    });
+
+    let drawn = draw_icon(cx, cx.update(|cx| StatusIcons::resolve(Status::Error, cx)));
+    assert!(
+        drawn.path.ends_with("/close-circle-fill.svg"),
+        "{}",
+        drawn.path
+    );
+    let cx = open_window(cx, |_, _| StatusIcon::new("status", Status::Error));
+    assert_eq!(draw_icons(cx)[0].color, gpui::red());
+}
+
+#[gpui::test]
+fn only_loading_icons_spin(cx: &mut TestAppContext) {
+    for status in Status::ALL {
+        let cx = open_window(cx, move |_, _| StatusIcon::new("status", status));
+        let spinning = draw_icons(cx)[0].transformation.is_some();
+        assert_eq!(spinning, status == Status::Loading, "{status:?}");
+    }
+}
+
+#[gpui::test]
+fn transitions_fade_in_status_changes(cx: &mut TestAppContext) {
+    let status = Rc::new(Cell::new(Status::Idle));
+    fn open(
+        cx: &mut TestAppContext,
+        status: Rc<Cell<Status>>,
+        transition: bool,
+    ) -> &mut VisualTestContext {
+        open_window(cx, move |_, _| {
+            StatusIcon::new("status", status.get()).transition(transition)
+        })
+    }
+    let opacity = |cx: &mut VisualTestContext| draw_icons(cx)[0].color.a;
+
+    // The first status appears without a transition
+    let mut app = cx.clone();
+    let faded = open(cx, status.clone(), true);
+    let instant = open(&mut app, status.clone(), false);
+    assert_eq!(opacity(faded), 1.);
+
+    status.set(Status::Success);
+    assert_eq!(opacity(faded), 0.);
+    assert_eq!(opacity(instant), 1.);
+    faded.executor().advance_clock(Duration::from_millis(100));
+    assert_eq!(opacity(faded), ease_out_quint()(0.5));
+    faded.executor().advance_clock(Duration::from_millis(100));
+    assert_eq!(opacity(faded), 1.);
+}
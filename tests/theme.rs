@@ -0,0 +1,91 @@
+//! Resolution of icon roles against the app's theme.
+
+#![cfg(feature = "arrows")]
+
+use gpui::{Styled as _, TestAppContext};
+use gpui_remixicon::test_support::draw_icon;
+use gpui_remixicon::{Icon, IconRole, IconTheme, arrows};
+
+const ROLES: [IconRole; 5] = [
+    IconRole::Muted,
+    IconRole::Accent,
+    IconRole::Danger,
+    IconRole::Success,
+    IconRole::Warning,
+];
+
+#[gpui::test]
+fn roles_default_without_a_theme(cx: &mut TestAppContext) {
+    for role in ROLES {
+        assert_eq!(
+            cx.update(|cx| IconTheme::resolve(role, cx)),
+            IconTheme::default().color(role),
+            "{role:?}"
+        );
+    }
+}
+
+#[gpui::test]
+fn roles_color_icons(cx: &mut TestAppContext) {
+    let drawn = draw_icon(
+        cx,
+        Icon::new(arrows::Icon::ArrowUpLine).role(IconRole::Danger),
+    );
+    assert_eq!(drawn.color, IconTheme::default().danger);
+
+    // An explicit color wins over the role
+    let drawn = draw_icon(
+        cx,
+        Icon::new(arrows::Icon::ArrowUpLine)
+            .role(IconRole::Muted)
+            .text_color(gpui::red()),
+    );
+    assert_eq!(drawn.color, gpui::red());
+}
+
+#[gpui::test]
+fn icon_theme_global_wins(cx: &mut TestAppContext) {
+    let theme = IconTheme {
+        danger: gpui::red(),
+        ..IconTheme::default()
+    };
+    cx.update(|cx| cx.set_global(theme));
+
+    let drawn = draw_icon(
+        cx,
+        Icon::new(arrows::Icon::ArrowUpLine).role(IconRole::Danger),
+    );
+    assert_eq!(drawn.color, gpui::red());
+}
+
+#[cfg(feature = "gpui-component")]
+#[gpui::test]
+fn roles_follow_gpui_component_theme(cx: &mut TestAppContext) {
+    let color = |hue| gpui::hsla(hue, 0.5, 0.5, 1.);
+    cx.update(|cx| {
+        gpui_component::init(cx);
+        let theme = cx.global_mut::<gpui_component::Theme>();
+        theme.muted_foreground = color(0.1);
+        theme.primary = color(0.2);
+        theme.danger = color(0.3);
+        theme.success = color(0.4);
+        theme.warning = color(0.5);
+    });
+
+    for (role, expected) in ROLES.into_iter().zip([0.1, 0.2, 0.3, 0.4, 0.5]) {
+        assert_eq!(
+            cx.update(|cx| IconTheme::resolve(role, cx)),
+            color(expected),
+            "{role:?}"
+        );
+        let drawn = draw_icon(cx, Icon::new(arrows::Icon::ArrowUpLine).role(role));
+        assert_eq!(drawn.color, color(expected), "{role:?} drawn");
+    }
+
+    // An installed `IconTheme` takes precedence over gpui-component's theme
+    cx.update(|cx| cx.set_global(IconTheme::default()));
+    assert_eq!(
+        cx.update(|cx| IconTheme::resolve(IconRole::Danger, cx)),
+        IconTheme::default().danger
+    );
+}
@@ -0,0 +1,111 @@
+//! Updating icon views in place.
+
+#![cfg(feature = "system")]
+
+use gpui::{
+    Entity, Modifiers, TestAppContext, Transformation, VisualTestContext, point, px, radians, size,
+};
+use gpui_remixicon::test_support::{DrawnIcon, draw_icon, draw_icons, icons_drawn_by, open_window};
+use gpui_remixicon::{Icon, IconRole, IconView, Size, system};
+use std::f32::consts::FRAC_PI_2;
+
+/// Apply `update` to the view and return the icon of the frame it caused.
+fn redraw(
+    cx: &mut VisualTestContext,
+    view: &Entity<IconView>,
+    update: impl FnOnce(&mut IconView, &mut gpui::Context<IconView>),
+) -> DrawnIcon {
+    let mut icons = icons_drawn_by(cx, |cx| view.update(cx, update));
+    assert_eq!(icons.len(), 1, "expected a single redraw, drew {icons:?}");
+    icons.remove(0)
+}
+
+#[gpui::test]
+fn setters_rerender_the_view(cx: &mut TestAppContext) {
+    let (view, cx) = cx.add_window_view(|_, _| IconView::new(system::Icon::Loader4Line));
+
+    // Updates that don't notify leave the window alone
+    assert_eq!(icons_drawn_by(cx, |cx| view.update(cx, |_, _| {})), []);
+
+    let icon = redraw(cx, &view, |view, cx| {
+        view.set_icon(system::Icon::CheckLine, cx)
+    });
+    assert!(icon.path.ends_with("/check-line.svg"), "{}", icon.path);
+
+    let icon = redraw(cx, &view, |view, cx| view.set_color(Some(gpui::red()), cx));
+    assert_eq!(icon.color, gpui::red());
+
+    let icon = redraw(cx, &view, |view, cx| view.set_size(Some(Size::Large), cx));
+    assert_eq!(icon.bounds.size, size(px(24.), px(24.)));
+    assert_eq!(icon.color, gpui::red(), "color is kept");
+
+    let icon = redraw(cx, &view, |view, cx| view.set_spinning(true, cx));
+    assert!(icon.transformation.is_some());
+    let icon = redraw(cx, &view, |view, cx| view.set_spinning(false, cx));
+    assert_eq!(icon.transformation, None);
+}
+
+#[gpui::test]
+fn views_render_like_inline_icons(cx: &mut TestAppContext) {
+    // `Icon::view` renders the icon it was created from through `Render`, so it must draw the
+    // same as the `RenderOnce` icon, in every state
+    let icon = Icon::new(system::Icon::CheckLine)
+        .large()
+        .role(IconRole::Accent)
+        .transform(Transformation::translate(point(px(2.), px(0.))))
+        .rotate(radians(FRAC_PI_2))
+        .flip_horizontal()
+        .id("icon")
+        .hover_color(gpui::red());
+    let view = cx.update(|cx| icon.clone().view(cx));
+    let inline = open_window(cx, move |_, _| icon.clone());
+    let drawn = draw_icons(inline);
+    let view = open_window(inline, move |_, _| view.clone());
+    assert_eq!(draw_icons(view), drawn);
+
+    let center = drawn[0].bounds.center();
+    view.simulate_mouse_move(center, None, Modifiers::none());
+    let hovered = draw_icons(view);
+    assert_eq!(hovered[0].color, gpui::red());
+    inline.simulate_mouse_move(center, None, Modifiers::none());
+    assert_eq!(draw_icons(inline), hovered);
+}
+
+#[gpui::test]
+fn rotation_and_flips_update_custom_transformation(cx: &mut TestAppContext) {
+    let translation = Transformation::translate(point(px(2.), px(0.)));
+
+    assert_eq!(Icon::new(system::Icon::CheckLine).transformation(), None);
+    assert_eq!(
+        Icon::new(system::Icon::CheckLine)
+            .transform(translation)
+            .transformation(),
+        Some(translation)
+    );
+    let icon = Icon::new(system::Icon::CheckLine)
+        .transform(translation)
+        .rotate(radians(1.))
+        .flip_vertical();
+    let expected = translation
+        .with_rotation(radians(1.))
+        .with_scaling(size(1., -1.));
+    assert_eq!(icon.transformation(), Some(expected));
+    assert_eq!(draw_icon(cx, icon).transformation, Some(expected));
+
+    // Rotation and flips replace the rotation and scale of a custom transformation
+    let custom = Transformation::rotate(radians(0.5)).with_scaling(size(2., 2.));
+    assert_eq!(
+        Icon::new(system::Icon::CheckLine)
+            .transform(custom)
+            .rotate(radians(1.))
+            .transformation(),
+        Some(custom.with_rotation(radians(1.)))
+    );
+    assert_eq!(
+        Icon::new(system::Icon::CheckLine)
+            .transform(custom)
+            .flip_horizontal()
+            .transformation(),
+        Some(custom.with_scaling(size(-1., 1.)))
+    );
+}
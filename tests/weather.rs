@@ -0,0 +1,75 @@
+//! Weather condition icons.
+
+#![cfg(feature = "weather")]
+
+use gpui_remixicon::weather::{Icon, WeatherCondition, for_condition};
+
+#[test]
+fn wmo_codes() {
+    for (code, expected) in [
+        (0, WeatherCondition::Clear),
+        (1, WeatherCondition::MainlyClear),
+        (3, WeatherCondition::Overcast),
+        (48, WeatherCondition::Fog),
+        (57, WeatherCondition::FreezingDrizzle),
+        (65, WeatherCondition::HeavyRain),
+        (77, WeatherCondition::Snow),
+        (82, WeatherCondition::HeavyShowers),
+        (86, WeatherCondition::SnowShowers),
+        (99, WeatherCondition::ThunderstormWithHail),
+    ] {
+        assert_eq!(
+            WeatherCondition::from_wmo_code(code),
+            Some(expected),
+            "{code}"
+        );
+    }
+    for code in [4, 50, 100, 255] {
+        assert_eq!(WeatherCondition::from_wmo_code(code), None, "{code}");
+    }
+}
+
+#[test]
+fn provider_names() {
+    for (name, expected) in [
+        ("Clear", WeatherCondition::Clear),
+        ("clear-night", WeatherCondition::Clear),
+        ("Partly cloudy", WeatherCondition::PartlyCloudy),
+        ("partly-cloudy-day", WeatherCondition::PartlyCloudy),
+        ("mostly_sunny", WeatherCondition::MainlyClear),
+        ("Clouds", WeatherCondition::Overcast),
+        ("Thunderstorm", WeatherCondition::Thunderstorm),
+        ("Light rain", WeatherCondition::Rain),
+        ("SNOW", WeatherCondition::Snow),
+    ] {
+        assert_eq!(WeatherCondition::from_name(name), Some(expected), "{name}");
+    }
+    assert_eq!(WeatherCondition::from_name("volcanic ash"), None);
+    assert_eq!(WeatherCondition::from_name("day"), None);
+    assert_eq!(WeatherCondition::from_name(""), None);
+}
+
+#[test]
+fn icons_follow_day_and_night() {
+    assert_eq!(for_condition(WeatherCondition::Clear, false), Icon::SunLine);
+    assert_eq!(
+        for_condition(WeatherCondition::Clear, true),
+        Icon::MoonClearLine
+    );
+    assert_eq!(
+        for_condition(WeatherCondition::PartlyCloudy, false),
+        Icon::SunCloudyLine
+    );
+    assert_eq!(
+        for_condition(WeatherCondition::PartlyCloudy, true),
+        Icon::MoonCloudyLine
+    );
+    assert_eq!(
+        for_condition(WeatherCondition::Thunderstorm, true),
+        for_condition(WeatherCondition::Thunderstorm, false)
+    );
+    assert_eq!(
+        for_condition(WeatherCondition::HeavyRain, false),
+        Icon::HeavyShowersLine
+    );
+}
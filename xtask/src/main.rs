@@ -0,0 +1,710 @@
+//! Maintenance tasks for gpui-remixicon.
+//!
+//! ```bash
+//! cargo xtask sync-icons <path> [--version <version>] [--dry-run]
+//! cargo xtask codegen-report
+//! ```
+//!
+//! `sync-icons` regenerates `assets/icons` from an already-downloaded RemixIcon checkout or
+//! release tarball (`.tar.gz`/`.tgz`), without touching the network.
+//!
+//! `codegen-report` builds the library once per category feature (and with `all`) and prints a
+//! Markdown table of build time, rlib size and generated `icon_name.rs` size.
+
+use anyhow::{Context as _, Result, bail};
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Instant;
+
+#[path = "../../build/icon_changes.rs"]
+mod icon_changes;
+#[path = "../../build/svg.rs"]
+mod svg;
+
+/// SVG contents keyed by `(category directory, label)`.
+type IconSet = BTreeMap<(String, String), Vec<u8>>;
+
+const USAGE: &str = "usage: cargo xtask sync-icons <path> [--version <version>] [--dry-run]
+       cargo xtask codegen-report";
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+    match args.next().as_deref() {
+        Some("sync-icons") => {
+            let mut source = None;
+            let mut version = None;
+            let mut dry_run = false;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--version" => version = Some(args.next().context(USAGE)?),
+                    "--dry-run" => dry_run = true,
+                    _ if source.is_none() => source = Some(PathBuf::from(arg)),
+                    _ => bail!(USAGE),
+                }
+            }
+            sync_icons(&source.context(USAGE)?, version, dry_run)
+        }
+        Some("codegen-report") if args.next().is_none() => codegen_report(),
+        _ => bail!(USAGE),
+    }
+}
+
+/// Root of the gpui-remixicon repository.
+fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+/// Convert a category directory name to its module/feature name, matching `build.rs`.
+fn category_to_snake_case(name: &str) -> String {
+    name.replace(" & ", "_and_")
+        .replace(' ', "_")
+        .to_lowercase()
+}
+
+fn sync_icons(source: &Path, version: Option<String>, dry_run: bool) -> Result<()> {
+    let root = repo_root();
+    let icons_dir = root.join("assets/icons");
+
+    // Unpack tarballs into a scratch directory first
+    let extracted = if source.is_file() {
+        let dir = env::temp_dir().join(format!("remixicon-sync-{}", std::process::id()));
+        unpack_tarball(source, &dir)?;
+        Some(dir)
+    } else {
+        None
+    };
+    let upstream_root = extracted.as_deref().unwrap_or(source);
+
+    let result = (|| {
+        let upstream_icons = find_icons_dir(upstream_root)?;
+        let version = match version {
+            Some(version) => version,
+            None => read_package_version(upstream_icons.parent().unwrap())?,
+        };
+
+        let mut upstream = read_icon_set(&upstream_icons)?;
+        for content in upstream.values_mut() {
+            if let Ok(text) = std::str::from_utf8(content) {
+                *content = svg::strip_hard_coded_fills(text).into_bytes();
+            }
+        }
+        validate(&upstream)?;
+        let current = read_icon_set(&icons_dir)?;
+
+        let report = Report::new(&current, &upstream);
+        report.print(&version);
+
+        if dry_run {
+            println!("dry run: nothing written");
+            return Ok(());
+        }
+        write_icon_set(&icons_dir, &upstream)?;
+        update_metadata_version(&root.join("Cargo.toml"), &version)?;
+        let changes_path = root.join("assets/icon-changes.txt");
+        report.append_changes(&changes_path, &version)?;
+        // Catch renames build.rs can't turn into aliases now rather than at the next build
+        icon_changes::resolve_renames(&icon_changes::read_icon_changes(&changes_path), |icon| {
+            upstream.keys().any(|key| icon_name(key) == icon)
+        });
+        println!("updated assets/icons to RemixIcon {version}");
+        Ok(())
+    })();
+
+    if let Some(dir) = extracted {
+        fs::remove_dir_all(dir).ok();
+    }
+    result
+}
+
+fn unpack_tarball(path: &Path, dest: &Path) -> Result<()> {
+    let file = fs::File::open(path).with_context(|| format!("opening {}", path.display()))?;
+    let name = path.to_string_lossy();
+    if name.ends_with(".tar.gz") || name.ends_with(".tgz") {
+        tar::Archive::new(flate2::read::GzDecoder::new(file)).unpack(dest)
+    } else if name.ends_with(".tar") {
+        tar::Archive::new(file).unpack(dest)
+    } else {
+        bail!(
+            "{}: expected a directory, .tar, .tar.gz or .tgz",
+            path.display()
+        );
+    }
+    .with_context(|| format!("unpacking {}", path.display()))
+}
+
+/// Locate the `icons` directory of a RemixIcon checkout, which may be nested one level deep
+/// (as in GitHub release tarballs).
+fn find_icons_dir(root: &Path) -> Result<PathBuf> {
+    let candidate = root.join("icons");
+    if candidate.is_dir() {
+        return Ok(candidate);
+    }
+    for entry in fs::read_dir(root).with_context(|| format!("reading {}", root.display()))? {
+        let candidate = entry?.path().join("icons");
+        if candidate.is_dir() {
+            return Ok(candidate);
+        }
+    }
+    bail!("{}: no RemixIcon `icons` directory found", root.display())
+}
+
+/// Read the `version` field of the upstream `package.json`.
+fn read_package_version(root: &Path) -> Result<String> {
+    let path = root.join("package.json");
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("{}: pass --version to set it explicitly", path.display()))?;
+    content
+        .lines()
+        .find_map(|line| {
+            let value = line.trim().strip_prefix("\"version\"")?;
+            Some(
+                value
+                    .trim_start_matches([' ', ':'])
+                    .split('"')
+                    .nth(1)?
+                    .to_string(),
+            )
+        })
+        .with_context(|| format!("{}: missing \"version\"", path.display()))
+}
+
+fn read_icon_set(dir: &Path) -> Result<IconSet> {
+    let mut icons = IconSet::new();
+    if !dir.exists() {
+        return Ok(icons);
+    }
+    for category in fs::read_dir(dir).with_context(|| format!("reading {}", dir.display()))? {
+        let category = category?;
+        if !category.path().is_dir() {
+            continue;
+        }
+        let category_name = category.file_name().to_string_lossy().to_string();
+        for file in fs::read_dir(category.path())? {
+            let path = file?.path();
+            if path.extension().is_some_and(|ext| ext == "svg") {
+                let label = path.file_stem().unwrap().to_string_lossy().to_string();
+                let content =
+                    fs::read(&path).with_context(|| format!("reading {}", path.display()))?;
+                icons.insert((category_name.clone(), label), content);
+            }
+        }
+    }
+    Ok(icons)
+}
+
+/// Check that every upstream icon has a label usable as a Rust identifier and follows the SVG
+/// rules `build.rs` enforces.
+fn validate(icons: &IconSet) -> Result<()> {
+    let mut errors = Vec::new();
+    for ((category, label), content) in icons {
+        let file = format!("{category}/{label}.svg");
+        if label.is_empty()
+            || label.starts_with('-')
+            || label.ends_with('-')
+            || !label
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            errors.push(format!("{file}: label is not lowercase kebab-case"));
+        }
+        match std::str::from_utf8(content) {
+            Ok(text) => errors.extend(svg::validate_svg(&file, text)),
+            Err(_) => errors.push(format!("{file}: not valid UTF-8")),
+        }
+    }
+    if errors.is_empty() {
+        return Ok(());
+    }
+    bail!("invalid upstream icons:\n  {}", errors.join("\n  "))
+}
+
+/// Replace `dir` with `icons`, writing them next to it first so a failed write leaves the
+/// current icons in place.
+fn write_icon_set(dir: &Path, icons: &IconSet) -> Result<()> {
+    let staging = dir.with_extension("new");
+    let previous = dir.with_extension("old");
+    for scratch in [&staging, &previous] {
+        if scratch.exists() {
+            fs::remove_dir_all(scratch)
+                .with_context(|| format!("removing {}", scratch.display()))?;
+        }
+    }
+
+    for ((category, label), content) in icons {
+        let category_dir = staging.join(category);
+        fs::create_dir_all(&category_dir)?;
+        let path = category_dir.join(format!("{label}.svg"));
+        fs::write(&path, content).with_context(|| format!("writing {}", path.display()))?;
+    }
+
+    if dir.exists() {
+        fs::rename(dir, &previous).with_context(|| format!("moving {}", dir.display()))?;
+    }
+    fs::rename(&staging, dir).with_context(|| format!("moving {}", staging.display()))?;
+    if previous.exists() {
+        fs::remove_dir_all(&previous)
+            .with_context(|| format!("removing {}", previous.display()))?;
+    }
+    Ok(())
+}
+
+/// Rewrite `version` under `[package.metadata.remixicon]` in `Cargo.toml`.
+fn update_metadata_version(manifest: &Path, version: &str) -> Result<()> {
+    let content = fs::read_to_string(manifest)?;
+    let mut in_section = false;
+    let mut updated = false;
+    let lines: Vec<String> = content
+        .lines()
+        .map(|line| {
+            if line.starts_with('[') {
+                in_section = line.trim() == "[package.metadata.remixicon]";
+            } else if in_section && line.starts_with("version") {
+                updated = true;
+                return format!("version = \"{version}\"");
+            }
+            line.to_string()
+        })
+        .collect();
+    if !updated {
+        bail!(
+            "{}: missing `version` in [package.metadata.remixicon]",
+            manifest.display()
+        );
+    }
+    fs::write(manifest, lines.join("\n") + "\n")?;
+    Ok(())
+}
+
+/// The `category/label` name of an [`IconSet`] key, as used in `assets/icon-changes.txt`.
+fn icon_name((category, label): &(String, String)) -> String {
+    format!("{}/{}", category_to_snake_case(category), label)
+}
+
+/// Differences between the current and upstream icon sets, as `category/label` names.
+struct Report {
+    added: Vec<String>,
+    removed: Vec<String>,
+    renamed: Vec<(String, String)>,
+    changed: Vec<String>,
+    added_categories: Vec<String>,
+    removed_categories: Vec<String>,
+}
+
+impl Report {
+    fn new(current: &IconSet, upstream: &IconSet) -> Self {
+        let mut added: Vec<_> = upstream
+            .keys()
+            .filter(|k| !current.contains_key(*k))
+            .collect();
+        let mut removed: Vec<_> = current
+            .keys()
+            .filter(|k| !upstream.contains_key(*k))
+            .collect();
+        let changed = upstream
+            .iter()
+            .filter(|(key, content)| current.get(*key).is_some_and(|old| old != *content))
+            .map(|(key, _)| icon_name(key))
+            .collect();
+
+        // An icon removed and re-added with identical content in the same category was renamed
+        let mut renamed = Vec::new();
+        removed.retain(|old| {
+            let Some(index) = added
+                .iter()
+                .position(|new| new.0 == old.0 && upstream[*new] == current[*old])
+            else {
+                return true;
+            };
+            renamed.push((icon_name(old), icon_name(added.remove(index))));
+            false
+        });
+
+        let categories = |icons: &IconSet| {
+            icons
+                .keys()
+                .map(|(c, _)| c.clone())
+                .collect::<BTreeSet<_>>()
+        };
+        let (current_categories, upstream_categories) = (categories(current), categories(upstream));
+
+        Self {
+            added: added.into_iter().map(icon_name).collect(),
+            removed: removed.into_iter().map(icon_name).collect(),
+            renamed,
+            changed,
+            added_categories: upstream_categories
+                .difference(&current_categories)
+                .cloned()
+                .collect(),
+            removed_categories: current_categories
+                .difference(&upstream_categories)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    fn print(&self, version: &str) {
+        println!("RemixIcon {version}:");
+        println!("  {} added", self.added.len());
+        for icon in &self.added {
+            println!("    + {icon}");
+        }
+        println!("  {} removed", self.removed.len());
+        for icon in &self.removed {
+            println!("    - {icon}");
+        }
+        println!("  {} renamed", self.renamed.len());
+        for (old, new) in &self.renamed {
+            println!("    {old} -> {new}");
+        }
+        println!("  {} changed", self.changed.len());
+        for icon in &self.changed {
+            println!("    ~ {icon}");
+        }
+        for category in &self.added_categories {
+            println!(
+                "warning: new category `{category}` needs a `{}` feature in Cargo.toml",
+                category_to_snake_case(category)
+            );
+        }
+        for category in &self.removed_categories {
+            println!(
+                "warning: category `{category}` was removed; drop the `{}` feature from Cargo.toml",
+                category_to_snake_case(category)
+            );
+        }
+    }
+
+    /// Record additions, removals and renames in the upstream change table read by `build.rs`.
+    fn append_changes(&self, path: &Path, version: &str) -> Result<()> {
+        let mut content = fs::read_to_string(path).unwrap_or_default();
+        for icon in &self.added {
+            content.push_str(&format!("{version} added {icon}\n"));
+        }
+        for icon in &self.removed {
+            content.push_str(&format!("{version} removed {icon}\n"));
+        }
+        for (old, new) in &self.renamed {
+            content.push_str(&format!("{version} renamed {old} {new}\n"));
+        }
+        fs::write(path, content).with_context(|| format!("writing {}", path.display()))
+    }
+}
+
+fn codegen_report() -> Result<()> {
+    let root = repo_root();
+    let target_dir = root.join("target/codegen-report");
+
+    let mut feature_sets: Vec<String> = read_icon_set(&root.join("assets/icons"))?
+        .keys()
+        .map(|(category, _)| category_to_snake_case(category))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    feature_sets.push("all".to_string());
+
+    // Build dependencies up front, so the timings below only cover this crate
+    build_lib(&root, &target_dir, "all")?;
+
+    println!("| features | build time | rlib size | icon_name.rs size |");
+    println!("|---|---:|---:|---:|");
+    for features in &feature_sets {
+        let status = Command::new(env!("CARGO"))
+            .args([
+                "clean",
+                "--quiet",
+                "--package",
+                "gpui-remixicon",
+                "--target-dir",
+            ])
+            .arg(&target_dir)
+            .current_dir(&root)
+            .status()?;
+        if !status.success() {
+            bail!("cargo clean failed");
+        }
+
+        let start = Instant::now();
+        build_lib(&root, &target_dir, features)?;
+        let elapsed = start.elapsed();
+
+        let rlib = target_dir.join("debug/libgpui_remixicon.rlib");
+        let rlib_size = fs::metadata(&rlib)
+            .with_context(|| format!("reading {}", rlib.display()))?
+            .len();
+        let generated_size = fs::metadata(generated_icon_names(&target_dir)?)?.len();
+        println!(
+            "| `{features}` | {:.1} s | {} KiB | {} KiB |",
+            elapsed.as_secs_f64(),
+            rlib_size / 1024,
+            generated_size / 1024
+        );
+    }
+    Ok(())
+}
+
+/// Build only the library with exactly `features` enabled.
+fn build_lib(root: &Path, target_dir: &Path, features: &str) -> Result<()> {
+    let status = Command::new(env!("CARGO"))
+        .args([
+            "build",
+            "--quiet",
+            "--lib",
+            "--no-default-features",
+            "--features",
+        ])
+        .arg(features)
+        .arg("--target-dir")
+        .arg(target_dir)
+        .current_dir(root)
+        .status()?;
+    if !status.success() {
+        bail!("building with features `{features}` failed");
+    }
+    Ok(())
+}
+
+/// Locate the `icon_name.rs` written by the most recent build script run.
+fn generated_icon_names(target_dir: &Path) -> Result<PathBuf> {
+    let build_dir = target_dir.join("debug/build");
+    let mut newest = None;
+    for entry in
+        fs::read_dir(&build_dir).with_context(|| format!("reading {}", build_dir.display()))?
+    {
+        let entry = entry?;
+        if !entry
+            .file_name()
+            .to_string_lossy()
+            .starts_with("gpui-remixicon-")
+        {
+            continue;
+        }
+        let path = entry.path().join("out/icon_name.rs");
+        if let Ok(modified) = fs::metadata(&path).and_then(|meta| meta.modified())
+            && newest.as_ref().is_none_or(|(time, _)| modified > *time)
+        {
+            newest = Some((modified, path));
+        }
+    }
+    newest
+        .map(|(_, path)| path)
+        .with_context(|| format!("{}: no generated icon_name.rs", build_dir.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icon_changes::IconChange;
+
+    const ICON: &str = r#"<svg viewBox="0 0 24 24" fill="currentColor" xmlns="http://www.w3.org/2000/svg"><path d="M4 4h16v16H4z"/></svg>"#;
+
+    fn icon_set(icons: &[(&str, &str, &str)]) -> IconSet {
+        icons
+            .iter()
+            .map(|(category, label, content)| {
+                (
+                    (category.to_string(), label.to_string()),
+                    content.as_bytes().to_vec(),
+                )
+            })
+            .collect()
+    }
+
+    /// A fresh directory under the system temp dir, unique to this test.
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("xtask-{}-{name}", std::process::id()));
+        fs::remove_dir_all(&dir).ok();
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn validate_accepts_embedded_icons() {
+        let icons = read_icon_set(&repo_root().join("assets/icons")).unwrap();
+        assert!(!icons.is_empty());
+        validate(&icons).unwrap();
+    }
+
+    #[test]
+    fn validate_applies_build_rules() {
+        let err = validate(&icon_set(&[
+            ("System", "Bad_Label", ICON),
+            (
+                "System",
+                "black",
+                r##"<svg viewBox="0 0 24 24"><path fill="#000" d="M0 0h24v24z"/></svg>"##,
+            ),
+            (
+                "System",
+                "tiny",
+                r#"<svg viewBox="0 0 16 16" fill="currentColor"><path d="M0 0h16v16z"/></svg>"#,
+            ),
+            ("System", "text", "not an svg"),
+        ]))
+        .unwrap_err()
+        .to_string();
+        assert!(
+            err.contains("System/Bad_Label.svg: label is not lowercase kebab-case"),
+            "{err}"
+        );
+        assert!(err.contains(r##"hard-coded fill="#000""##), "{err}");
+        assert!(err.contains(r#"viewBox is "0 0 16 16""#), "{err}");
+        assert!(err.contains("System/text.svg:1:1: invalid SVG"), "{err}");
+    }
+
+    #[test]
+    fn hard_coded_fills_are_stripped() {
+        let upstream = r##"<svg viewBox="0 0 24 24" fill="currentColor"><path d="M4 4h16v16H4z" fill="#121212"/><path fill="none" d="M0 0"/></svg>"##;
+        assert_eq!(
+            svg::strip_hard_coded_fills(upstream),
+            r#"<svg viewBox="0 0 24 24" fill="currentColor"><path d="M4 4h16v16H4z"/><path fill="none" d="M0 0"/></svg>"#
+        );
+
+        // Icons that don't fill with currentColor are left for validation to reject
+        let black = r##"<svg viewBox="0 0 24 24"><path fill="#000" d="M0 0"/></svg>"##;
+        assert_eq!(svg::strip_hard_coded_fills(black), black);
+    }
+
+    #[test]
+    fn write_icon_set_swaps_directories() {
+        let root = scratch_dir("write");
+        let icons_dir = root.join("icons");
+        fs::create_dir_all(icons_dir.join("Old")).unwrap();
+        fs::write(icons_dir.join("Old/stale.svg"), ICON).unwrap();
+
+        write_icon_set(&icons_dir, &icon_set(&[("System", "add-line", ICON)])).unwrap();
+
+        assert_eq!(
+            read_icon_set(&icons_dir).unwrap(),
+            icon_set(&[("System", "add-line", ICON)])
+        );
+        let mut entries: Vec<_> = fs::read_dir(&root)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name())
+            .collect();
+        entries.sort();
+        assert_eq!(entries, ["icons"]);
+        fs::remove_dir_all(root).ok();
+    }
+
+    /// Resolve the renames of an `icon-changes.txt` fixture against the `existing` icons.
+    fn resolve_fixture(
+        name: &str,
+        fixture: &str,
+        existing: &[&str],
+    ) -> BTreeMap<String, (String, String)> {
+        let dir = scratch_dir(name);
+        let path = dir.join("icon-changes.txt");
+        fs::write(&path, fixture).unwrap();
+        let changes = icon_changes::read_icon_changes(&path);
+        fs::remove_dir_all(dir).ok();
+        icon_changes::resolve_renames(&changes, |icon| existing.contains(&icon))
+    }
+
+    fn alias(new: &str, version: &str) -> (String, String) {
+        (new.to_string(), version.to_string())
+    }
+
+    #[test]
+    fn icon_changes_are_parsed_in_file_order() {
+        let dir = scratch_dir("changes");
+        let path = dir.join("icon-changes.txt");
+        fs::write(
+            &path,
+            "# comment\n\n4.0.0 added system/new\n4.1.0  renamed system/a media/b\n4.2.0 removed system/new\n",
+        )
+        .unwrap();
+        assert_eq!(
+            icon_changes::read_icon_changes(&path),
+            [
+                (
+                    "4.0.0".to_string(),
+                    IconChange::Added("system/new".to_string())
+                ),
+                (
+                    "4.1.0".to_string(),
+                    IconChange::Renamed("system/a".to_string(), "media/b".to_string())
+                ),
+                (
+                    "4.2.0".to_string(),
+                    IconChange::Removed("system/new".to_string())
+                ),
+            ]
+        );
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn renames_resolve_to_current_icons() {
+        let aliases = resolve_fixture(
+            "renames",
+            "4.0.0 renamed system/first system/second
+4.1.0 renamed system/second system/third
+4.1.0 renamed system/dropped system/doomed
+4.2.0 removed system/doomed
+4.2.0 renamed system/moved media/moved
+",
+            &["system/third", "media/moved"],
+        );
+        assert_eq!(
+            aliases,
+            BTreeMap::from([
+                // Chains lead to the current name, each alias keeping the version of its rename
+                ("system/first".to_string(), alias("system/third", "4.0.0")),
+                ("system/second".to_string(), alias("system/third", "4.1.0")),
+                // Renames across categories
+                ("system/moved".to_string(), alias("media/moved", "4.2.0")),
+                // `system/dropped` leads to a removed icon and gets no alias
+            ])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "rename cycle involving `system/a`")]
+    fn rename_cycles_are_rejected() {
+        resolve_fixture(
+            "cycle",
+            "4.0.0 renamed system/a system/b\n4.1.0 renamed system/b system/a\n",
+            &[],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`system/a` was renamed to `system/b`, which does not exist")]
+    fn renames_to_missing_icons_are_rejected() {
+        resolve_fixture("missing", "4.0.0 renamed system/a system/b\n", &[]);
+    }
+
+    #[test]
+    #[should_panic(expected = "`system/a` is renamed more than once")]
+    fn duplicate_renames_are_rejected() {
+        resolve_fixture(
+            "duplicate",
+            "4.0.0 renamed system/a system/b\n4.1.0 renamed system/a system/c\n",
+            &["system/b", "system/c"],
+        );
+    }
+
+    #[test]
+    fn report_detects_renames() {
+        let current = icon_set(&[("System", "old-name", ICON), ("System", "gone", "<svg/>")]);
+        let upstream = icon_set(&[
+            ("System", "new-name", ICON),
+            ("Health & Medical", "added", "<svg></svg>"),
+        ]);
+        let report = Report::new(&current, &upstream);
+        assert_eq!(
+            report.renamed,
+            [("system/old-name".to_string(), "system/new-name".to_string())]
+        );
+        assert_eq!(report.added, ["health_and_medical/added"]);
+        assert_eq!(report.removed, ["system/gone"]);
+        assert_eq!(report.added_categories, ["Health & Medical"]);
+    }
+}